@@ -1,10 +1,10 @@
 use core::f32::consts::E as E32;
 use core::f64::consts::E as E64;
-use core::ops::RangeBounds;
+use core::ops::{Range, RangeBounds};
 use criterion::{
     black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
-use lambert_w::{lambert_w0, lambert_wm1};
+use lambert_w::{lambert_w0, lambert_w0_uniform, lambert_wm1};
 use lambert_w::{lambert_w0f, lambert_wm1f, sp_lambert_w0, sp_lambert_wm1};
 use rand::{
     distr::uniform::{SampleRange, SampleUniform},
@@ -51,6 +51,40 @@ fn bench_on_vec_of_random_values_in_range<'a, R, T, F, Prng>(
     });
 }
 
+/// Generates a vec of random values whose magnitudes are spread evenly over the decades between
+/// 10^`exponents.start` and 10^`exponents.end`, converts them with `convert`, and benchmarks the given function on them.
+///
+/// Consecutive values are likely to be in different subdomains of the approximations,
+/// so this measures the cost of a mispredicted selection of the subdomain.
+fn bench_on_vec_of_values_spread_across_decades<'a, T, F, C, Prng>(
+    group: &mut BenchmarkGroup<'a, WallTime>,
+    id: &str,
+    f: F,
+    exponents: Range<f64>,
+    convert: C,
+    rng: &mut Prng,
+) where
+    T: Copy,
+    F: Fn(T) -> T,
+    C: Fn(f64) -> T,
+    Prng: Rng,
+{
+    group.bench_function(id, |b| {
+        b.iter_custom(|iters| {
+            let datas: Vec<T> = (0..iters)
+                .map(|_| convert(10.0_f64.powf(rng.random_range(exponents.clone()))))
+                .collect();
+            let start = Instant::now();
+            for &z in &datas {
+                black_box(f(z));
+            }
+            let duration = start.elapsed();
+            drop(datas);
+            duration
+        })
+    });
+}
+
 fn random_benches(c: &mut Criterion) {
     let mut group = c.benchmark_group("random inputs");
     let mut rng = SmallRng::seed_from_u64(0b1010101010101);
@@ -63,6 +97,14 @@ fn random_benches(c: &mut Criterion) {
         &mut rng,
     );
 
+    bench_on_vec_of_random_values_in_range(
+        &mut group,
+        "W_0 branch-free",
+        lambert_w0_uniform,
+        0.0..f64::MAX,
+        &mut rng,
+    );
+
     bench_on_vec_of_random_values_in_range(
         &mut group,
         "W_0 24 bits",
@@ -102,6 +144,45 @@ fn random_benches(c: &mut Criterion) {
         -1.0 / E32..=0.0,
         &mut rng,
     );
+    group.finish();
+
+    let mut group = c.benchmark_group("random inputs spread across decades");
+
+    bench_on_vec_of_values_spread_across_decades(
+        &mut group,
+        "W_0 50 bits",
+        lambert_w0,
+        -1.0..300.0,
+        |z| z,
+        &mut rng,
+    );
+
+    bench_on_vec_of_values_spread_across_decades(
+        &mut group,
+        "W_0 24 bits on f32",
+        lambert_w0f,
+        -1.0..38.0,
+        |z| z as f32,
+        &mut rng,
+    );
+
+    bench_on_vec_of_values_spread_across_decades(
+        &mut group,
+        "W_-1 50 bits",
+        lambert_wm1,
+        -300.0..-0.5,
+        |z| -z,
+        &mut rng,
+    );
+
+    bench_on_vec_of_values_spread_across_decades(
+        &mut group,
+        "W_-1 24 bits on f32",
+        lambert_wm1f,
+        -37.0..-0.5,
+        |z| -z as f32,
+        &mut rng,
+    );
 }
 
 criterion_group!(benches, random_benches);
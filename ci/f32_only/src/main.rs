@@ -0,0 +1,20 @@
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+use core::panic::PanicInfo;
+
+use lambert_w::{lambert_w0f, lambert_wm1f};
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    loop {
+        black_box(lambert_w0f(black_box(1.0)));
+        black_box(lambert_wm1f(black_box(-0.1)));
+    }
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
@@ -2,7 +2,7 @@
 //! It generates a plot of the two branches of the function
 //! and saves it as a png file.
 
-use lambert_w::{LambertW, NEG_INV_E};
+use lambert_w::{lambert_w0_linspace, LambertW, NEG_INV_E};
 use plotters::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -20,26 +20,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     chart.configure_mesh().x_labels(20).y_labels(20).draw()?;
 
-    let steps: u32 = 10000;
     chart
         .draw_series(LineSeries::new(
-            (0..steps)
-                // Convert step index into number between 0 and 1.
-                .map(|x| f64::from(x) / f64::from(steps))
-                // Map it to the x-axis.
-                .map(|t| t * (10.0 - NEG_INV_E) + NEG_INV_E)
-                // Apply the principal branch of the Lambert W function
-                // to the x-value.
-                .map(|x| (x, x.lambert_w0())),
+            // Evenly spaced x-values along the x-axis paired with
+            // the principal branch of the Lambert W function at them.
+            lambert_w0_linspace(NEG_INV_E, 10.0, 10_000),
             &BLACK,
         ))?
         .label("W_0(x)")
         .legend(|(x, y)| Rectangle::new([(x - 5, y), (x + 10, y)], BLACK));
 
+    let steps: u32 = 10000;
     chart
         .draw_series(LineSeries::new(
             (0..steps)
+                // Convert step index into number between 0 and 1.
                 .map(|x| f64::from(x) / f64::from(steps))
+                // Map it to the x-axis.
                 .map(|t| NEG_INV_E - t * (NEG_INV_E + 0.073))
                 .map(|x| (x, x.lambert_wm1())),
             &RED,
@@ -49,8 +46,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     chart
         .configure_series_labels()
-        .background_style(&BLACK.mix(0.1))
-        .border_style(&BLACK)
+        .background_style(BLACK.mix(0.1))
+        .border_style(BLACK)
         .draw()?;
 
     root.present()?;
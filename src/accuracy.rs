@@ -0,0 +1,88 @@
+//! The accuracy tiers of the approximations.
+
+/// The accuracy of an approximation of the Lambert W function,
+/// e.g. for a [`LambertWSolver`](crate::LambertWSolver) or [`lambert_w0_with_accuracy`](crate::lambert_w0_with_accuracy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Accuracy {
+    /// The approximations with 24 bits of accuracy, e.g. [`sp_lambert_w0`](crate::sp_lambert_w0).
+    Bits24,
+    /// The approximations with 50 bits of accuracy, e.g. [`lambert_w0`](crate::lambert_w0).
+    Bits50,
+    /// The approximations with 50 bits of accuracy refined with one iteration by Fritsch, Shafer and Crowley,
+    /// which brings them to about the precision of `f64`.
+    Full,
+}
+
+/// An accuracy of the approximations of the Lambert W function as a type, which selects the approximations
+/// that [`lambert_w0_with`](crate::lambert_w0_with) and [`lambert_wm1_with`](crate::lambert_wm1_with) evaluate at compile time.
+///
+/// Lets generic code be parameterized over the accuracy without any branches at runtime.
+/// The trait is sealed, so that more tiers and methods can be added to it without breaking changes.
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::{lambert_w0_with, Accuracy, Accuracy24, Accuracy50, AccuracyTier};
+///
+/// fn solve<A: AccuracyTier>(z: f64) -> (f64, Accuracy) {
+///     (lambert_w0_with::<A>(z), A::ACCURACY)
+/// }
+///
+/// assert_eq!(solve::<Accuracy24>(1.0).1, Accuracy::Bits24);
+/// assert_eq!(solve::<Accuracy50>(1.0).1, Accuracy::Bits50);
+/// ```
+pub trait AccuracyTier: private::Sealed {
+    /// The accuracy of the tier as a value.
+    const ACCURACY: Accuracy;
+
+    /// The principal branch of the Lambert W function computed to the accuracy of the tier.
+    fn lambert_w0(z: f64) -> f64;
+
+    /// The secondary branch of the Lambert W function computed to the accuracy of the tier.
+    fn lambert_wm1(z: f64) -> f64;
+}
+
+/// The approximations with 24 bits of accuracy, [`sp_lambert_w0`](crate::sp_lambert_w0) and [`sp_lambert_wm1`](crate::sp_lambert_wm1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Accuracy24;
+
+impl AccuracyTier for Accuracy24 {
+    const ACCURACY: Accuracy = Accuracy::Bits24;
+
+    #[inline(always)]
+    fn lambert_w0(z: f64) -> f64 {
+        crate::sp_lambert_w0(z)
+    }
+
+    #[inline(always)]
+    fn lambert_wm1(z: f64) -> f64 {
+        crate::sp_lambert_wm1(z)
+    }
+}
+
+/// The approximations with 50 bits of accuracy, [`lambert_w0`](crate::lambert_w0) and [`lambert_wm1`](crate::lambert_wm1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Accuracy50;
+
+impl AccuracyTier for Accuracy50 {
+    const ACCURACY: Accuracy = Accuracy::Bits50;
+
+    #[inline(always)]
+    fn lambert_w0(z: f64) -> f64 {
+        crate::lambert_w0(z)
+    }
+
+    #[inline(always)]
+    fn lambert_wm1(z: f64) -> f64 {
+        crate::lambert_wm1(z)
+    }
+}
+
+mod private {
+    /// Prevents implementations of [`AccuracyTier`](super::AccuracyTier) outside of the crate.
+    pub trait Sealed {}
+
+    impl Sealed for super::Accuracy24 {}
+    impl Sealed for super::Accuracy50 {}
+}
@@ -0,0 +1,194 @@
+//! Runtime construction of polynomial approximations of the Lambert W function on a narrow input interval.
+//!
+//! The functions at the crate root are valid on the entire domain of their branch, and must first determine which
+//! subdomain an input belongs to. When all inputs are known to lie in a narrow interval, a single polynomial
+//! of low degree fitted to that interval can be cheaper to evaluate. The functions in this module construct
+//! such a polynomial at runtime: the Chebyshev interpolant of the chosen branch on the interval, truncated to the lowest
+//! degree that meets a requested absolute error. Chebyshev interpolants are close to the minimax polynomial of the same degree.
+//!
+//! Only available with the `std` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # use approx::assert_abs_diff_eq;
+//! use lambert_w::{approx_builder, lambert_w0};
+//!
+//! let w0 = approx_builder::build_w0(1.0, 2.0, 1e-12).unwrap();
+//!
+//! assert!(w0.degree() < 20);
+//! assert_abs_diff_eq!(w0.eval(1.5), lambert_w0(1.5), epsilon = 1e-12);
+//! ```
+//!
+//! Inputs outside the interval of the approximation result in [`NAN`](f64::NAN):
+//!
+//! ```
+//! # use lambert_w::approx_builder;
+//! let w0 = approx_builder::build_w0(1.0, 2.0, 1e-12).unwrap();
+//!
+//! assert!(w0.eval(3.0).is_nan());
+//! ```
+
+use std::vec::Vec;
+
+use crate::{elementary::cos, lambert_w0, lambert_wm1, NEG_INV_E};
+
+/// The degree of the interpolant that is truncated to find the approximation.
+/// If that interpolant does not meet the requested error, no approximation is returned.
+const MAX_DEGREE: usize = 128;
+
+/// The number of points per coefficient of the full interpolant at which the error of the approximations is measured.
+const CHECK_POINTS_PER_COEFFICIENT: usize = 4;
+
+/// A polynomial approximation of a branch of the Lambert W function on a closed interval.
+///
+/// Created by [`build_w0`] or [`build_wm1`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Approximation {
+    lo: f64,
+    hi: f64,
+    coefficients: Vec<f64>,
+}
+
+impl Approximation {
+    /// Evaluates the approximation at `z`.
+    ///
+    /// Returns [`NAN`](f64::NAN) if `z` is outside the interval of the approximation.
+    pub fn eval(&self, z: f64) -> f64 {
+        if !(self.lo..=self.hi).contains(&z) {
+            return f64::NAN;
+        }
+
+        clenshaw(&self.coefficients, self.to_unit_interval(z))
+    }
+
+    /// Returns the degree of the polynomial.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Returns the endpoints of the interval that the approximation is valid on.
+    pub fn interval(&self) -> (f64, f64) {
+        (self.lo, self.hi)
+    }
+
+    /// Maps the interval of the approximation onto [-1, 1].
+    fn to_unit_interval(&self, z: f64) -> f64 {
+        ((2.0 * z - self.lo - self.hi) / (self.hi - self.lo)).clamp(-1.0, 1.0)
+    }
+}
+
+/// Constructs a polynomial approximation of the principal branch of the Lambert W function on the closed interval from `lo` to `hi`,
+/// whose absolute error is at most `max_error`.
+///
+/// Returns `None` if the interval is empty, not finite, or extends below -1/e,
+/// or if no polynomial of degree at most 128 meets the requested error.
+/// Since the function has a square root singularity at -1/e, intervals that contain or are close to
+/// that point need a much higher degree than other intervals of the same width.
+///
+/// The function is sampled with [`lambert_w0`], so errors smaller than about 1e-15 times the largest
+/// function value on the interval can not be met.
+pub fn build_w0(lo: f64, hi: f64, max_error: f64) -> Option<Approximation> {
+    if lo < NEG_INV_E || lo.is_nan() {
+        return None;
+    }
+    build(lambert_w0, lo, hi, max_error)
+}
+
+/// Constructs a polynomial approximation of the secondary branch of the Lambert W function on the closed interval from `lo` to `hi`,
+/// whose absolute error is at most `max_error`.
+///
+/// Returns `None` if the interval is empty, or not contained in [-1/e, 0),
+/// or if no polynomial of degree at most 128 meets the requested error.
+/// Since the function has a square root singularity at -1/e and a logarithmic singularity at 0,
+/// intervals that are close to either of those points need a much higher degree than other intervals of the same width.
+///
+/// The function is sampled with [`lambert_wm1`], so errors smaller than about 1e-15 times the largest
+/// function magnitude on the interval can not be met.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::{approx_builder, lambert_wm1};
+///
+/// let wm1 = approx_builder::build_wm1(-0.3, -0.2, 1e-10).unwrap();
+///
+/// assert_abs_diff_eq!(wm1.eval(-0.25), lambert_wm1(-0.25), epsilon = 1e-10);
+/// ```
+pub fn build_wm1(lo: f64, hi: f64, max_error: f64) -> Option<Approximation> {
+    if lo < NEG_INV_E || lo.is_nan() || hi >= 0.0 {
+        return None;
+    }
+    build(lambert_wm1, lo, hi, max_error)
+}
+
+fn build(f: fn(f64) -> f64, lo: f64, hi: f64, max_error: f64) -> Option<Approximation> {
+    if !(lo < hi && hi.is_finite() && max_error > 0.0) {
+        return None;
+    }
+
+    let n = MAX_DEGREE + 1;
+    let half_width = 0.5 * (hi - lo);
+    let midpoint = 0.5 * (hi + lo);
+
+    // Sample the function at the Chebyshev points of the first kind.
+    let angles: Vec<f64> = (0..n)
+        .map(|k| core::f64::consts::PI * (k as f64 + 0.5) / n as f64)
+        .collect();
+    let samples: Vec<f64> = angles
+        .iter()
+        .map(|&a| f((midpoint + half_width * cos(a)).clamp(lo, hi)))
+        .collect();
+
+    let mut coefficients: Vec<f64> = (0..n)
+        .map(|j| {
+            let sum: f64 = angles
+                .iter()
+                .zip(&samples)
+                .map(|(&a, &s)| s * cos(j as f64 * a))
+                .sum();
+            2.0 * sum / n as f64
+        })
+        .collect();
+    coefficients[0] *= 0.5;
+
+    // The error of the approximations is measured against the function on a uniform grid that is
+    // denser than the Chebyshev points of the full interpolant.
+    let points = CHECK_POINTS_PER_COEFFICIENT * n;
+    let grid: Vec<(f64, f64)> = (0..=points)
+        .map(|i| {
+            let z = (lo + (hi - lo) * i as f64 / points as f64).clamp(lo, hi);
+            (z, f(z))
+        })
+        .collect();
+
+    // Return the lowest degree truncation of the interpolant that meets the requested error.
+    (1..=n).find_map(|len| {
+        let approximation = Approximation {
+            lo,
+            hi,
+            coefficients: coefficients[..len].to_vec(),
+        };
+        let error = grid
+            .iter()
+            .map(|&(z, w)| (approximation.eval(z) - w).abs())
+            // NAN compares false, so an approximation with non-finite values is never accepted.
+            .fold(
+                0.0,
+                |max: f64, e| if max.is_nan() || e <= max { max } else { e },
+            );
+        (error <= max_error).then_some(approximation)
+    })
+}
+
+/// Evaluates the Chebyshev series with the given coefficients at `x` in [-1, 1] with Clenshaw's recurrence.
+fn clenshaw(coefficients: &[f64], x: f64) -> f64 {
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &c in coefficients[1..].iter().rev() {
+        let b0 = c + 2.0 * x * b1 - b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    coefficients[0] + x * b1 - b2
+}
@@ -0,0 +1,98 @@
+//! The real branches of the Lambert W function.
+
+use crate::{error::BranchIndexError, NEG_INV_E};
+
+/// A real branch of the Lambert W function, for selecting the branch to evaluate at runtime,
+/// e.g. with [`lambert_w`](crate::lambert_w).
+///
+/// With the `serde` feature the type implements `Serialize` and `Deserialize`, as the names of the variants.
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::{lambert_w, lambert_w0, lambert_wm1, Branch};
+///
+/// for (branch, w) in [(Branch::W0, lambert_w0(-0.2)), (Branch::Wm1, lambert_wm1(-0.2))] {
+///     assert_eq!(lambert_w(branch, -0.2), w);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Branch {
+    /// The principal branch, W0, which is defined for arguments from -1/e and takes values from -1.
+    W0,
+    /// The secondary branch, W-1, which is defined for arguments from -1/e to 0 and takes values up to -1.
+    Wm1,
+}
+
+impl Branch {
+    /// Returns the index of the branch, which is 0 for [`W0`](Branch::W0) and -1 for [`Wm1`](Branch::Wm1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lambert_w::Branch;
+    ///
+    /// assert_eq!(Branch::Wm1.index(), -1);
+    /// assert_eq!(Branch::try_from(Branch::W0.index()), Ok(Branch::W0));
+    /// ```
+    pub const fn index(self) -> i64 {
+        match self {
+            Self::W0 => 0,
+            Self::Wm1 => -1,
+        }
+    }
+
+    /// Returns whether the branch has a real value at `z`, which is the case for finite `z` from -1/e
+    /// for [`W0`](Branch::W0), and for `z` from -1/e up to but not including 0 for [`Wm1`](Branch::Wm1).
+    ///
+    /// -1/e is represented by [`NEG_INV_E`], the smallest argument that the functions of this crate accept.
+    ///
+    /// These are the arguments where the functions of the branch return a finite value.
+    /// At -0.0, which is equal to 0, [`lambert_wm1`](crate::lambert_wm1) returns -∞, the limit of the branch at 0,
+    /// so this returns `false` for [`Wm1`](Branch::Wm1) there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lambert_w::{Branch, NEG_INV_E};
+    ///
+    /// assert!(Branch::W0.is_real_at(1.0));
+    /// assert!(!Branch::Wm1.is_real_at(1.0));
+    /// assert!(Branch::Wm1.is_real_at(NEG_INV_E));
+    /// assert!(!Branch::W0.is_real_at(-1.0));
+    /// assert!(Branch::W0.is_real_at(-0.0));
+    /// assert!(!Branch::Wm1.is_real_at(-0.0));
+    /// ```
+    pub fn is_real_at(self, z: f64) -> bool {
+        match self {
+            Self::W0 => (NEG_INV_E..f64::INFINITY).contains(&z),
+            Self::Wm1 => (NEG_INV_E..0.0).contains(&z),
+        }
+    }
+}
+
+/// Converts a branch index, e.g. from a configuration file, to a [`Branch`].
+///
+/// Only the branches with index 0 and -1 have real values, so other indices result in a [`BranchIndexError`].
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::Branch;
+///
+/// assert_eq!(Branch::try_from(-1), Ok(Branch::Wm1));
+/// assert_eq!(Branch::try_from(1).unwrap_err().index(), 1);
+/// ```
+impl TryFrom<i64> for Branch {
+    type Error = BranchIndexError;
+
+    fn try_from(index: i64) -> Result<Self, Self::Error> {
+        match index {
+            0 => Ok(Self::W0),
+            -1 => Ok(Self::Wm1),
+            index => Err(BranchIndexError { index }),
+        }
+    }
+}
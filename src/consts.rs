@@ -0,0 +1,258 @@
+//! Constants related to the Lambert W function.
+//!
+//! The floating point constants have been rounded to the closest available value of their type,
+//! so they can be used directly instead of converting the `f64` constants to `f32` in user code.
+//!
+//! This module also contains the values of the Lambert W function at the boundaries between the subdomains
+//! used in the piecewise approximations of the function.
+
+/// The negative inverse of e (-1/e).
+///
+/// This is the smallest input value for which the Lambert W functions in this crate return a value.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::NEG_INV_E;
+///
+/// assert_abs_diff_eq!(NEG_INV_E, -f64::exp(-1.0));
+/// ```
+//            Rounded from -0.367_879_441_171_442_322
+pub const NEG_INV_E: f64 = -0.367_879_441_171_442_32;
+
+/// The difference between -1/e and [`NEG_INV_E`], such that the sum of the two is -1/e to about 33 significant digits.
+pub(crate) const NEG_INV_E_LO: f64 = 1.242_875_367_278_836_3e-17;
+
+/// The negative inverse of e (-1/e) as an `f32`.
+///
+/// This is the smallest input value for which the Lambert W functions in this crate that act on `f32`s return a value.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::NEG_INV_E_F32;
+///
+/// assert_abs_diff_eq!(NEG_INV_E_F32, -f32::exp(-1.0));
+/// ```
+//                Rounded from -0.367_879_441_171_442_322
+pub const NEG_INV_E_F32: f32 = -0.367_879_45;
+
+/// 1/sqrt(e).
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::INV_SQRT_E;
+///
+/// assert_abs_diff_eq!(INV_SQRT_E * INV_SQRT_E, f64::exp(-1.0));
+/// ```
+//            Rounded from 0.606_530_659_712_633_423
+pub const INV_SQRT_E: f64 = 0.606_530_659_712_633_4;
+
+/// 1/sqrt(e) as an `f32`.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::INV_SQRT_E_F32;
+///
+/// assert_abs_diff_eq!(INV_SQRT_E_F32 * INV_SQRT_E_F32, f32::exp(-1.0));
+/// ```
+//                Rounded from 0.606_530_659_712_633_423
+pub const INV_SQRT_E_F32: f32 = 0.606_530_67;
+
+/// The omega constant (Ω).
+///
+/// Fulfills the equation Ωe^Ω = 1.
+///
+/// Has been rounded to the closest available `f64` value.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::OMEGA;
+///
+/// assert_abs_diff_eq!(OMEGA * f64::exp(OMEGA), 1.0);
+/// ```
+//        Rounded from 0.567_143_290_409_783_87
+pub const OMEGA: f64 = 0.567_143_290_409_783_8;
+// If we round the last two digits (87) to 9 rustc sets the constant to
+//                     0.567_143_290_409_784
+// which is further away from the true value than what we get if we round them to 8.
+
+/// The omega constant (Ω) as an `f32`.
+///
+/// Fulfills the equation Ωe^Ω = 1.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::OMEGA_F32;
+///
+/// assert_abs_diff_eq!(OMEGA_F32 * f32::exp(OMEGA_F32), 1.0);
+/// ```
+//            Rounded from 0.567_143_290_409_783_87
+pub const OMEGA_F32: f32 = 0.567_143_26;
+
+/// e^(1/e), the largest base for which the infinite power tower converges.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::E_POW_INV_E;
+///
+/// assert_abs_diff_eq!(E_POW_INV_E.ln(), f64::exp(-1.0));
+/// ```
+//              Rounded from 1.444_667_861_009_766_133_66
+pub const E_POW_INV_E: f64 = 1.444_667_861_009_766;
+
+/// e^(-e), the smallest base for which the infinite power tower converges.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::consts::E_POW_NEG_E;
+///
+/// assert_abs_diff_eq!(E_POW_NEG_E.ln(), -core::f64::consts::E);
+/// ```
+//              Rounded from 0.065_988_035_845_312_537_08
+pub const E_POW_NEG_E: f64 = 0.065_988_035_845_312_54;
+
+/// The values of the principal branch at the upper boundaries of the subdomains
+/// in the approximation with 50 bits of accuracy.
+///
+/// Subdomain number `n` (counting from zero) covers inputs whose function value is at most `W0_BREAKPOINTS[n]`,
+/// and is larger than the previous breakpoint. The subdomain after the last breakpoint extends to infinity.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{consts::W0_BREAKPOINTS, lambert_w0};
+///
+/// for w in W0_BREAKPOINTS {
+///     assert_relative_eq!(lambert_w0(w * w.exp()), w, max_relative = 1e-14);
+/// }
+/// ```
+pub const W0_BREAKPOINTS: [f64; 18] = [
+    0.893_196_827_565_503_8,
+    2.753_890_973_500_704_7,
+    4.820_795_104_395_194,
+    7.041_497_672_608_328,
+    9.379_844_106_361_54,
+    11.808_558_302_214_953,
+    14.308_052_444_545_252,
+    16.864_565_528_815_51,
+    19.468_306_835_547_978,
+    22.112_114_866_694_153,
+    24.790_587_844_054_095,
+    27.499_539_062_201_332,
+    30.235_642_371_281_063,
+    32.996_204_098_742_25,
+    35.779_000_252_974_306,
+    38.582_170_295_710_08,
+    41.404_134_105_700_91,
+    234.357_509_987_680_77,
+];
+
+/// The values of the secondary branch at the lower boundaries of the subdomains
+/// in the approximation with 50 bits of accuracy.
+///
+/// Subdomain number `n` (counting from zero) covers inputs whose function value is at least `WM1_BREAKPOINTS[n]`,
+/// and is smaller than the previous breakpoint. The subdomain after the last breakpoint extends to negative infinity.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{consts::WM1_BREAKPOINTS, lambert_wm1};
+///
+/// for w in WM1_BREAKPOINTS {
+///     assert_relative_eq!(lambert_wm1(w * w.exp()), w, max_relative = 1e-14);
+/// }
+/// ```
+pub const WM1_BREAKPOINTS: [f64; 10] = [
+    -1.3,
+    -2.637_155_093_464_933,
+    -4.252_707_459_358_246,
+    -5.831_667_947_830_437,
+    -7.381_679_762_021_36,
+    -8.912_786_431_964_98,
+    -10.432_666_875_410_446,
+    -11.946_339_583_100_336,
+    -41.343_997_561_683_835,
+    -185.316_793_319_420_55,
+];
+
+/// The values of the principal branch at the upper boundaries of the subdomains
+/// in the approximation with 24 bits of accuracy.
+///
+/// Subdomain number `n` (counting from zero) covers inputs whose function value is at most `SP_W0_BREAKPOINTS[n]`,
+/// and is larger than the previous breakpoint. The subdomain after the last breakpoint extends to infinity.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{consts::SP_W0_BREAKPOINTS, sp_lambert_w0};
+///
+/// for w in SP_W0_BREAKPOINTS {
+///     assert_relative_eq!(sp_lambert_w0(w * w.exp()), w, max_relative = 1e-7);
+/// }
+/// ```
+pub const SP_W0_BREAKPOINTS: [f64; 18] = [
+    0.854_493_753_927_381_2,
+    2.501_941_984_538_006_6,
+    4.429_678_855_079_174,
+    6.573_525_979_975_622_5,
+    8.891_504_732_042_499,
+    11.351_229_648_007_036,
+    13.928_422_547_813_18,
+    16.605_167_749_133_493,
+    19.368_080_618_639_432,
+    22.206_922_262_914_247,
+    25.113_660_433_947_56,
+    28.081_845_982_212_307,
+    31.106_195_918_405_81,
+    34.182_307_767_227_02,
+    37.306_455_602_267_84,
+    40.475_448_076_292_054,
+    43.686_518_640_445_53,
+    319.673_018_497_742_53,
+];
+
+/// The values of the secondary branch at the lower boundaries of the subdomains
+/// in the approximation with 24 bits of accuracy.
+///
+/// Subdomain number `n` (counting from zero) covers inputs whose function value is at least `SP_WM1_BREAKPOINTS[n]`,
+/// and is smaller than the previous breakpoint. The subdomain after the last breakpoint extends to negative infinity.
+///
+/// # Example
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{consts::SP_WM1_BREAKPOINTS, sp_lambert_wm1};
+///
+/// for w in SP_WM1_BREAKPOINTS {
+///     assert_relative_eq!(sp_lambert_wm1(w * w.exp()), w, max_relative = 1e-7);
+/// }
+/// ```
+pub const SP_WM1_BREAKPOINTS: [f64; 9] = [
+    -2.483_143_623_970_814_2,
+    -4.032_282_718_718_511,
+    -5.600_213_077_947_835,
+    -7.177_614_966_232_569,
+    -8.765_634_013_937_511,
+    -10.366_783_687_651_163,
+    -11.982_735_280_340_572,
+    -47.518_342_353_819_26,
+    -317.993_476_179_912_25,
+];
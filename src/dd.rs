@@ -0,0 +1,294 @@
+//! Evaluation of the Lambert W function in double-double arithmetic.
+
+use core::cmp::Ordering;
+
+use crate::{
+    elementary::{ln, mul_add},
+    lambert_w0, lambert_w0_near_branch, lambert_wm1, lambert_wm1_near_branch,
+    refine::halley,
+};
+
+// The functions in this file compute an initial approximation with the 50 bit approximation
+// on f64, and then perform two steps of Newton's method on ln|w| + w - ln|z| = 0 in double-double arithmetic.
+// Working with the logarithm instead of the exponential keeps all intermediate values in range
+// for every argument, and each Newton step doubles the number of correct bits.
+// Close to the branch point the initial approximation is instead computed from the offset of the argument
+// from -1/e, since the seed must then be accurate relative to W + 1.
+
+/// ln(2) as a double-double number.
+const LN_2: DoubleDouble = DoubleDouble {
+    hi: core::f64::consts::LN_2,
+    lo: 2.319_046_813_846_299_6e-17,
+};
+
+/// -1/e as a double-double number.
+const NEG_INV_E: DoubleDouble = DoubleDouble {
+    hi: -0.367_879_441_171_442_33,
+    lo: 1.242_875_367_278_836_3e-17,
+};
+
+/// Below this offset from the branch point the initial approximation is computed from the offset.
+const NEAR_BRANCH_ZC: f64 = 0.01;
+
+/// Below this magnitude of the argument the principal branch is evaluated with its Taylor series.
+const SERIES_Z: f64 = 1e-11;
+
+/// A double-double number: the unevaluated sum of two `f64`s `hi` and `lo`,
+/// where `lo` is at most half an ulp of `hi`.
+///
+/// This represents numbers with about 106 bits, or 32 decimal digits, of precision,
+/// but with the same exponent range as an `f64`.
+///
+/// Only available with the `dd` feature.
+///
+/// # Examples
+///
+/// ```
+/// use lambert_w::DoubleDouble;
+///
+/// let x = DoubleDouble::from_sum(1.0, 1e-20);
+///
+/// assert_eq!(x.hi(), 1.0);
+/// assert_eq!(x.lo(), 1e-20);
+/// assert_eq!(f64::from(x), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Creates the double-double number that is the exact sum of `a` and `b`.
+    pub fn from_sum(a: f64, b: f64) -> Self {
+        two_sum(a, b)
+    }
+
+    /// Returns the leading part of the number, which is the number rounded to the nearest `f64`.
+    pub const fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the trailing part of the number.
+    pub const fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Returns `true` if the number is NaN.
+    pub fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+
+    fn add(self, other: Self) -> Self {
+        let s = two_sum(self.hi, other.hi);
+        let t = two_sum(self.lo, other.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    fn mul(self, other: Self) -> Self {
+        let p = two_prod(self.hi, other.hi);
+        quick_two_sum(p.hi, p.lo + (self.hi * other.lo + self.lo * other.hi))
+    }
+
+    fn div(self, other: Self) -> Self {
+        let q1 = self.hi / other.hi;
+        let r = self.sub(other.mul(q1.into()));
+        let q2 = r.hi / other.hi;
+        let r = r.sub(other.mul(q2.into()));
+        let q3 = r.hi / other.hi;
+        quick_two_sum(q1, q2).add(q3.into())
+    }
+
+    /// Multiplies the number by 2^`n`. Exact as long as neither part overflows or underflows.
+    fn scale(self, n: i32) -> Self {
+        // Scale in two steps, since 2^n may itself be out of range for results that are in range.
+        let (f1, f2) = (pow2(n / 2), pow2(n - n / 2));
+        Self {
+            hi: self.hi * f1 * f2,
+            lo: self.lo * f1 * f2,
+        }
+    }
+
+    /// Returns the natural logarithm of a positive finite number.
+    fn ln(self) -> Self {
+        if self.hi == 0.0 {
+            return f64::NEG_INFINITY.into();
+        } else if self.hi < f64::MIN_POSITIVE {
+            // Subnormal numbers are scaled up to normal numbers first.
+            return self.scale(54).ln().sub(LN_2.mul(54.0.into()));
+        }
+
+        // Write the number as m·2^e with m close to 1, so that ln(m) is small
+        // and ln(x) = ln(m) + e·ln(2).
+        let e = exponent(self.hi);
+        let m = self.scale(-e);
+        let (m, e) = if m.hi > core::f64::consts::SQRT_2 {
+            (m.scale(-1), e + 1)
+        } else {
+            (m, e)
+        };
+
+        // One Newton step on e^y = m from the f64 logarithm doubles the number of correct bits.
+        let y = DoubleDouble::from(ln(m.hi));
+        let y = y.add(m.mul(y.neg().exp_small())).sub(1.0.into());
+
+        y.add(LN_2.mul(f64::from(e).into()))
+    }
+
+    /// Returns the exponential of a number with a magnitude of at most 1.
+    fn exp_small(self) -> Self {
+        const SQUARINGS: i32 = 9;
+
+        // Compute e^(x/2^9) - 1 with its Taylor series, and then square 1 plus that 9 times.
+        let s = self.scale(-SQUARINGS);
+        let mut term = s;
+        let mut em1 = s;
+        for k in 2..=10 {
+            term = term.mul(s).div(f64::from(k).into());
+            em1 = em1.add(term);
+        }
+        for _ in 0..SQUARINGS {
+            // (1 + p)^2 - 1 = p·(2 + p)
+            em1 = em1.mul(em1.add(2.0.into()));
+        }
+
+        em1.add(1.0.into())
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        Self { hi: x, lo: 0.0 }
+    }
+}
+
+impl From<DoubleDouble> for f64 {
+    /// Rounds the double-double number to the nearest `f64`.
+    fn from(x: DoubleDouble) -> Self {
+        x.hi + x.lo
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ord => ord,
+        }
+    }
+}
+
+/// Computes the sum of two `f64`s exactly as a double-double number.
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let s = a + b;
+    let bb = s - a;
+    DoubleDouble {
+        hi: s,
+        lo: (a - (s - bb)) + (b - bb),
+    }
+}
+
+/// Computes the sum of two `f64`s exactly as a double-double number, if |`a`| >= |`b`|.
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let s = a + b;
+    DoubleDouble {
+        hi: s,
+        lo: b - (s - a),
+    }
+}
+
+/// Computes the product of two `f64`s exactly as a double-double number.
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let p = a * b;
+    DoubleDouble {
+        hi: p,
+        lo: mul_add(a, b, -p),
+    }
+}
+
+/// Returns 2^`n` for `n` in the range of the exponents of normal `f64`s.
+fn pow2(n: i32) -> f64 {
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+/// Returns the exponent e of a positive normal `x`, such that x/2^e is in [1, 2).
+fn exponent(x: f64) -> i32 {
+    (x.to_bits() >> 52) as i32 - 1023
+}
+
+pub fn w0_dd(z: DoubleDouble) -> DoubleDouble {
+    let zc = z.sub(NEG_INV_E);
+    if z.is_nan() || zc.hi < 0.0 {
+        return f64::NAN.into();
+    } else if zc.hi == 0.0 {
+        return (-1.0).into();
+    } else if z.hi == 0.0 || z.hi == f64::INFINITY {
+        return z;
+    } else if z.hi.abs() < SERIES_Z {
+        // W0(z) = z - z^2 + 3/2·z^3 - 8/3·z^4 + ...
+        let z2 = z.mul(z);
+        return z.sub(z2).add(z2.mul(z).mul(1.5.into()));
+    }
+
+    let w = if zc.hi <= NEAR_BRANCH_ZC {
+        DoubleDouble::from_sum(lambert_w0_near_branch(zc.hi), -1.0)
+    } else {
+        let w = lambert_w0(z.hi);
+        if w < 1.0 {
+            // The approximation has an absolute rather than relative error close to zero,
+            // so a step of Halley's method first makes the relative error of the seed small.
+            halley(z.hi, w).into()
+        } else {
+            w.into()
+        }
+    };
+
+    newton(newton(w, z), z)
+}
+
+pub fn wm1_dd(z: DoubleDouble) -> DoubleDouble {
+    let zc = z.sub(NEG_INV_E);
+    if z.hi == 0.0 && z.hi.is_sign_negative() {
+        return f64::NEG_INFINITY.into();
+    } else if z.is_nan() || zc.hi < 0.0 || z.hi >= 0.0 {
+        return f64::NAN.into();
+    } else if zc.hi == 0.0 {
+        return (-1.0).into();
+    }
+
+    let w = if zc.hi <= NEAR_BRANCH_ZC {
+        DoubleDouble::from_sum(lambert_wm1_near_branch(zc.hi), -1.0)
+    } else {
+        lambert_wm1(z.hi).into()
+    };
+
+    newton(newton(w, z), z)
+}
+
+/// Performs one step of Newton's method on ln|w| + w - ln|z| = 0.
+/// The arguments must be non-zero, finite and of the same sign.
+fn newton(w: DoubleDouble, z: DoubleDouble) -> DoubleDouble {
+    let (abs_w, abs_z) = if z.hi < 0.0 {
+        (w.neg(), z.neg())
+    } else {
+        (w, z)
+    };
+    let g = abs_w.ln().add(w).sub(abs_z.ln());
+    let wp1 = w.add(1.0.into());
+    if wp1.hi == 0.0 {
+        return w;
+    }
+    w.sub(g.mul(w).div(wp1))
+}
@@ -0,0 +1,74 @@
+//! Evaluation of the principal branch of the Lambert W function on [`Decimal`] numbers.
+
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, MathematicalOps,
+};
+
+use crate::{error::DomainError, lambert_w0, lambert_w0_near_branch};
+
+// This file computes an initial approximation with the 50 bit approximation on f64,
+// and then performs steps of Newton's method in decimal arithmetic until the result stops changing.
+// For arguments up to 1 the Newton step is written as (w - z·e^(-w))/(w + 1),
+// and for larger arguments as (w + ln(w) - ln(z))·w/(w + 1). Neither overflows,
+// and the latter does not lose digits to the small factor e^(-w) that a `Decimal` only holds
+// to a fixed number of decimals.
+
+/// -1/e rounded to the 28 decimals that a `Decimal` can hold.
+const NEG_INV_E: Decimal = Decimal::from_parts(2_384_059_206, 2_857_938_002, 199_427_844, true, 28);
+
+/// Below this offset from the branch point the initial approximation is computed from the offset.
+const NEAR_BRANCH_ZC: f64 = 0.01;
+
+/// The initial approximation has 50 bits of accuracy, and each step doubles the number of correct digits,
+/// so this is enough to reach the 28 to 29 significant digits of a `Decimal`.
+/// The remaining steps only matter if the last digit oscillates between two values.
+const MAX_STEPS: usize = 5;
+
+pub fn w0_decimal(z: Decimal) -> Result<Decimal, DomainError> {
+    if z < NEG_INV_E {
+        return Err(DomainError);
+    } else if z == NEG_INV_E {
+        return Ok(Decimal::NEGATIVE_ONE);
+    } else if z.is_zero() {
+        return Ok(z);
+    }
+
+    // The offset is exact in decimal arithmetic, and does not overflow since z is at most -1/e away from zero.
+    let zc = (z - NEG_INV_E).to_f64().unwrap_or(f64::NAN);
+    let seed = if zc <= NEAR_BRANCH_ZC {
+        lambert_w0_near_branch(zc) - 1.0
+    } else {
+        lambert_w0(z.to_f64().unwrap_or(f64::NAN))
+    };
+    let mut w = Decimal::from_f64(seed).ok_or(DomainError)?;
+
+    let ln_z = if z > Decimal::ONE {
+        z.checked_ln()
+    } else {
+        None
+    };
+
+    for _ in 0..MAX_STEPS {
+        let wp1 = w + Decimal::ONE;
+        if wp1.is_zero() {
+            break;
+        }
+        let step = match ln_z {
+            Some(ln_z) => match w.checked_ln() {
+                Some(ln_w) => (w + ln_w - ln_z) * w / wp1,
+                None => break,
+            },
+            None => match (-w).checked_exp() {
+                Some(emw) => (w - z * emw) / wp1,
+                None => break,
+            },
+        };
+        if step.is_zero() {
+            break;
+        }
+        w -= step;
+    }
+
+    Ok(w)
+}
@@ -0,0 +1,53 @@
+//! Evaluation of the branches of the Lambert W function on the dual numbers of the `num-dual` crate.
+
+use num_dual::DualNum;
+
+use crate::{lambert_w0, lambert_wm1};
+
+// The functions in this file evaluate the function on the real part of the dual number with the
+// 50 bit approximation, and then compute the derivative parts.
+// For dual numbers with derivatives of at most second order this is done with the Taylor expansion
+// W(z0 + h) = W(z0) + W'(z0)·h + W''(z0)·h²/2, where h is the non-real part of the argument and the derivatives have the closed forms
+// W'(z) = W(z)/(z·(1 + W(z))) and W''(z) = -W'(z)²·(2 + W(z))/(1 + W(z)).
+// The expansion is exact since h³ = 0 for such dual numbers.
+// For higher orders the derivative parts are computed with Newton's method on w·e^w = z carried out in dual arithmetic.
+// The real part of the residual is set to zero in every step, so only the derivative parts are iterated.
+// Each step doubles the number of orders of derivatives that are exact.
+
+pub fn w0_dual<D: DualNum<Primitive = f64>>(z: D) -> D {
+    refine(z, lambert_w0)
+}
+
+pub fn wm1_dual<D: DualNum<Primitive = f64>>(z: D) -> D {
+    refine(z, lambert_wm1)
+}
+
+fn refine<D: DualNum<Primitive = f64>>(z: D, f: fn(f64) -> f64) -> D {
+    let z0 = z.re();
+    let w0 = f(z0);
+    // The derivatives are infinite at the branch point. That, and arguments outside the domain,
+    // make every part of the result NAN.
+    if w0.is_nan() || w0 == -1.0 {
+        return z * f64::NAN;
+    }
+
+    if D::NDERIV <= 2 {
+        let wp1 = w0 + 1.0;
+        // W(z)/z is 1 at z = 0.
+        let first = if z0 == 0.0 { 1.0 } else { w0 / (z0 * wp1) };
+        let second = -first * first * (w0 + 2.0) / wp1;
+        let h = z.clone() - z0;
+        return h.clone() * (h * (0.5 * second) + first) + w0;
+    }
+
+    let mut w = D::from(w0);
+    let mut derivatives = 0;
+    while derivatives < D::NDERIV {
+        let ew = w.exp();
+        let mut residual = w.clone() * ew.clone() - z.clone();
+        residual -= residual.re();
+        w -= residual / (ew * (w.clone() + 1.0));
+        derivatives = 2 * derivatives + 1;
+    }
+    w
+}
@@ -1,5 +1,5 @@
 use crate::{
-    elementary::{ln, sqrt},
+    kernel::MathKernel,
     rational::{rational_7_over_7, rational_8_over_7},
 };
 
@@ -9,68 +9,30 @@ use crate::{
 // It returns f64::NAN if the input is negative or NAN,
 // and f64::INFINITY if the input is positive infinity.
 
+// The correctly rounded values of the function at the seams between the subdomains
+// where the approximations on either side of the seam would otherwise make the function decrease.
+// The approximations are clamped to these values, which only changes them close to the seam,
+// and there only by less than their error.
+const W_X1_X2: f64 = 0.893_196_827_565_503_8;
+const W_X2_X3: f64 = 2.753_890_973_500_704_7;
+const W_X3_X4: f64 = 4.820_795_104_395_194;
+const W_X17_U18: f64 = 41.404_134_105_700_91;
+
 /// zc = z + 1/e
-pub fn dw0c(zc: f64) -> f64 {
+pub fn dw0c<K: MathKernel>(zc: f64) -> f64 {
     if zc < 0.0 || zc.is_nan() {
         f64::NAN
     } else if zc <= 2.549_893_906_503_473_6 {
         // W <= 0.893, X_1
-
-        rational_8_over_7(
-            sqrt(zc),
-            [
-                -0.999_999_999_999_999_9,
-                -2.739_966_866_820_366,
-                0.026_164_207_726_990_4,
-                6.370_916_807_894_901,
-                7.101_328_651_785_403,
-                2.980_082_678_300_685_3,
-                0.488_195_968_137_898_7,
-                0.023_753_035_787_333_61,
-                0.000_077_365_760_093_772_43,
-            ],
-            [
-                1.,
-                5.071_610_848_417_428,
-                9.986_838_818_354_528,
-                9.660_755_192_207_887,
-                4.794_372_899_133_612,
-                1.162_970_347_770_452_3,
-                0.118_494_625_007_337_55,
-                0.003_432_652_513_240_222_5,
-            ],
-        )
+        x1::<K>(zc)
     } else if zc <= 43.613_924_462_669_37 {
         // W <= 2.754, X_2
-
-        rational_7_over_7(
-            sqrt(zc),
-            [
-                -0.999_978_018_005_789_1,
-                -0.704_157_515_904_836,
-                2.123_226_083_280_252_8,
-                2.389_676_070_293_572,
-                0.777_653_118_050_291_8,
-                0.089_686_698_993_644_75,
-                0.003_306_248_575_374_64,
-                0.000_025_106_760_479_132_852,
-            ],
-            [
-                1.,
-                3.035_602_682_808_541,
-                3.143_453_015_128_678,
-                1.372_315_656_659_244_7,
-                0.258_446_974_157_442_1,
-                0.019_551_162_251_819_045,
-                0.000_487_759_332_445_301_26,
-                2.316_511_684_107_315_5e-6,
-            ],
-        )
+        x2::<K>(zc)
     } else if zc <= 598.453_533_718_782_8 {
         // W <= 4.821, X_3
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 -0.989_674_203_372_735,
                 0.595_876_806_063_943_8,
@@ -92,11 +54,12 @@ pub fn dw0c(zc: f64) -> f64 {
                 2.575_066_733_701_592_3e-9,
             ],
         )
+        .clamp(W_X2_X3, W_X3_X4)
     } else if zc <= 8_049.491_985_075_761_5 {
         // W <= 7.041, X_4
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 -0.773_164_919_972_062_3,
                 1.139_133_350_429_670_3,
@@ -118,11 +81,12 @@ pub fn dw0c(zc: f64) -> f64 {
                 9.279_423_101_326_45e-13,
             ],
         )
+        .max(W_X3_X4)
     } else if zc <= 111_124.954_121_217_82 {
         // W <= 9.380, X_5
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 0.120_071_016_715_536_88,
                 0.833_526_408_299_128_3,
@@ -148,7 +112,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 11.809, X_6
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 1.722_110_443_993_771_1,
                 0.399_195_942_864_842_8,
@@ -174,7 +138,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 14.308, X_7
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 3.752_931_402_343_454_3,
                 0.154_913_426_903_578_07,
@@ -200,7 +164,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 16.865, X_8
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 6.019_654_205_560_656,
                 0.053_496_672_841_797_86,
@@ -226,7 +190,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 19.468, X_9
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 8.428_026_850_098_97,
                 0.017_155_758_546_279_713,
@@ -252,7 +216,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 22.112, X_10
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 10.931_063_230_472_498,
                 0.005_222_423_454_024_553_5,
@@ -278,7 +242,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 24.791, X_11
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 13.502_943_080_893_871,
                 0.001_528_463_650_634_626_6,
@@ -304,7 +268,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 27.500, X_12
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 16.128_076_167_439_016,
                 0.000_433_603_851_764_670_7,
@@ -330,7 +294,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 30.236, X_13
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 18.796_301_105_534_486,
                 0.000_119_894_433_396_464_69,
@@ -356,7 +320,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 32.996, X_14
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 21.500_582_830_667_334,
                 0.000_032_441_943_237_735_277,
@@ -382,7 +346,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 35.779, X_15
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 24.235_812_532_416_976,
                 8.616_150_599_577_68e-6,
@@ -408,7 +372,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 38.582, X_16
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 26.998_134_347_987_44,
                 2.251_225_776_757_228_4e-6,
@@ -434,7 +398,7 @@ pub fn dw0c(zc: f64) -> f64 {
         // W <= 41.404, X_17
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 29.784_546_702_831_97,
                 5.797_176_439_217_133e-7,
@@ -456,58 +420,157 @@ pub fn dw0c(zc: f64) -> f64 {
                 4.007_296_402_524_44e-67,
             ],
         )
+        .min(W_X17_U18)
     } else if zc <= 1.412_707_514_527_465_2e104 {
         // W <= 234.358, U_18
-
-        rational_7_over_7(
-            ln(zc),
-            [
-                0.744_134_994_601_267_8,
-                0.414_032_436_180_059_14,
-                0.260_125_641_667_734_16,
-                0.021_450_457_095_960_294,
-                0.000_518_723_772_647_059_1,
-                4.357_469_356_831_998e-6,
-                1.236_306_605_892_170_7e-8,
-                9.019_414_776_630_996e-12,
-            ],
-            [
-                1.,
-                0.334_878_110_674_670_1,
-                0.023_756_834_394_570_627,
-                0.000_542_256_330_089_077_3,
-                4.437_898_005_257_962e-6,
-                1.243_658_549_766_81e-8,
-                9.022_582_586_763_186e-12,
-                -4.205_783_627_010_972e-19,
-            ],
-        )
+        u18::<K>(zc)
     } else if zc < f64::INFINITY {
         //   U_19
+        u19::<K>(zc)
+    } else {
+        f64::INFINITY
+    }
+}
 
-        rational_7_over_7(
-            ln(zc),
-            [
-                -0.615_144_128_127_297_6,
-                0.679_793_101_336_309_3,
-                0.089_685_353_704_585_82,
-                0.001_564_494_148_398_938,
-                7.734_990_187_817_636e-6,
-                1.289_164_754_669_943_5e-8,
-                7.089_032_598_897_381_6e-12,
-                9.841_979_033_427_972e-16,
-            ],
-            [
-                1.,
-                0.097_300_263_710_401_44,
-                0.001_610_367_274_844_206,
-                7.824_774_100_307_7e-6,
-                1.294_926_130_897_134_6e-8,
-                7.098_691_121_934_283e-12,
-                9.842_628_504_222_704e-16,
-                -1.596_014_725_260_605_6e-24,
-            ],
-        )
+/// The approximation on the subdomain X_1, where W <= 0.893.
+#[inline(always)]
+fn x1<K: MathKernel>(zc: f64) -> f64 {
+    rational_8_over_7(
+        K::sqrt(zc),
+        [
+            -0.999_999_999_999_999_9,
+            -2.739_966_866_820_366,
+            0.026_164_207_726_990_4,
+            6.370_916_807_894_901,
+            7.101_328_651_785_403,
+            2.980_082_678_300_685_3,
+            0.488_195_968_137_898_7,
+            0.023_753_035_787_333_61,
+            0.000_077_365_760_093_772_43,
+        ],
+        [
+            1.,
+            5.071_610_848_417_428,
+            9.986_838_818_354_528,
+            9.660_755_192_207_887,
+            4.794_372_899_133_612,
+            1.162_970_347_770_452_3,
+            0.118_494_625_007_337_55,
+            0.003_432_652_513_240_222_5,
+        ],
+    )
+    .min(W_X1_X2)
+}
+
+/// The approximation on the subdomain X_2, where 0.893 < W <= 2.754.
+#[inline(always)]
+fn x2<K: MathKernel>(zc: f64) -> f64 {
+    rational_7_over_7(
+        K::sqrt(zc),
+        [
+            -0.999_978_018_005_789_1,
+            -0.704_157_515_904_836,
+            2.123_226_083_280_252_8,
+            2.389_676_070_293_572,
+            0.777_653_118_050_291_8,
+            0.089_686_698_993_644_75,
+            0.003_306_248_575_374_64,
+            0.000_025_106_760_479_132_852,
+        ],
+        [
+            1.,
+            3.035_602_682_808_541,
+            3.143_453_015_128_678,
+            1.372_315_656_659_244_7,
+            0.258_446_974_157_442_1,
+            0.019_551_162_251_819_045,
+            0.000_487_759_332_445_301_26,
+            2.316_511_684_107_315_5e-6,
+        ],
+    )
+    .clamp(W_X1_X2, W_X2_X3)
+}
+
+/// The approximation on the subdomain U_18, where 41.404 < W <= 234.358.
+#[inline(always)]
+fn u18<K: MathKernel>(zc: f64) -> f64 {
+    rational_7_over_7(
+        K::ln(zc),
+        [
+            0.744_134_994_601_267_8,
+            0.414_032_436_180_059_14,
+            0.260_125_641_667_734_16,
+            0.021_450_457_095_960_294,
+            0.000_518_723_772_647_059_1,
+            4.357_469_356_831_998e-6,
+            1.236_306_605_892_170_7e-8,
+            9.019_414_776_630_996e-12,
+        ],
+        [
+            1.,
+            0.334_878_110_674_670_1,
+            0.023_756_834_394_570_627,
+            0.000_542_256_330_089_077_3,
+            4.437_898_005_257_962e-6,
+            1.243_658_549_766_81e-8,
+            9.022_582_586_763_186e-12,
+            -4.205_783_627_010_972e-19,
+        ],
+    )
+    .max(W_X17_U18)
+}
+
+/// The approximation on the subdomain U_19, where W > 234.358.
+#[inline(always)]
+fn u19<K: MathKernel>(zc: f64) -> f64 {
+    rational_7_over_7(
+        K::ln(zc),
+        [
+            -0.615_144_128_127_297_6,
+            0.679_793_101_336_309_3,
+            0.089_685_353_704_585_82,
+            0.001_564_494_148_398_938,
+            7.734_990_187_817_636e-6,
+            1.289_164_754_669_943_5e-8,
+            7.089_032_598_897_381_6e-12,
+            9.841_979_033_427_972e-16,
+        ],
+        [
+            1.,
+            0.097_300_263_710_401_44,
+            0.001_610_367_274_844_206,
+            7.824_774_100_307_7e-6,
+            1.294_926_130_897_134_6e-8,
+            7.098_691_121_934_283e-12,
+            9.842_628_504_222_704e-16,
+            -1.596_014_725_260_605_6e-24,
+        ],
+    )
+}
+
+/// Like [`dw0c`], but only with the first two subdomains, which end at zc = 43.61.
+/// Returns f64::NAN for larger inputs.
+pub fn dw0c_small<K: MathKernel>(zc: f64) -> f64 {
+    if zc < 0.0 || zc.is_nan() {
+        f64::NAN
+    } else if zc <= 2.549_893_906_503_473_6 {
+        x1::<K>(zc)
+    } else if zc <= 43.613_924_462_669_37 {
+        x2::<K>(zc)
+    } else {
+        f64::NAN
+    }
+}
+
+/// Like [`dw0c`], but only with the last two subdomains, which are functions of ln(zc) and start at zc = 3.97e19.
+/// Returns f64::NAN for smaller inputs.
+pub fn dw0c_large<K: MathKernel>(zc: f64) -> f64 {
+    if zc <= 3.968_539_219_834_401_6e19 || zc.is_nan() {
+        f64::NAN
+    } else if zc <= 1.412_707_514_527_465_2e104 {
+        u18::<K>(zc)
+    } else if zc < f64::INFINITY {
+        u19::<K>(zc)
     } else {
         f64::INFINITY
     }
@@ -1,6 +1,6 @@
-use super::{INV_SQRT_E, NEG_INV_E};
 use crate::{
-    elementary::{ln, sqrt},
+    consts::{INV_SQRT_E, NEG_INV_E},
+    kernel::MathKernel,
     rational::rational_7_over_7,
 };
 
@@ -8,17 +8,18 @@ use crate::{
 // branch of the Lambert W function
 // with 50 bits of accuracy from Fukushima's paper.
 // It returns f64::NAN if the `zc` input is negative,
-// or if the `z` input is NAN, or larger than or equal to 0.
+// or if the `z` input is NAN, or larger than or equal to 0,
+// except for -0.0 where it returns f64::NEG_INFINITY.
 
 /// zc = z + 1/e
-pub fn dwm1c(z: f64, zc: f64) -> f64 {
+pub fn dwm1c<K: MathKernel>(z: f64, zc: f64) -> f64 {
     if zc < 0.0 {
         f64::NAN
     } else if z <= -0.354_291_330_944_216_4 {
         // W >= -1.3, X_-1
 
         rational_7_over_7(
-            sqrt(zc),
+            K::sqrt(zc),
             [
                 -1.000_000_000_000_000_111_0,
                 4.296_301_617_877_713,
@@ -44,7 +45,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -2.637, Y_-1
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -8.225_315_526_444_685,
                 -813.207_067_320_014_9,
@@ -70,7 +71,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -4.253, Y_-2
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -9.618_412_744_335_403,
                 -3_557.856_904_301_800_6,
@@ -96,7 +97,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -5.832, Y_-3
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -11.038_489_462_297_466,
                 -15_575.812_882_656_619,
@@ -122,7 +123,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -7.382, Y_-4
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -12.474_405_916_395_746,
                 -68_180.335_575_543_78,
@@ -148,7 +149,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -8.913, Y_-5
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -13.921_651_376_890_072,
                 -298_789.564_823_880_7,
@@ -174,7 +175,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -10.433, Y_-6
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -15.377_894_224_591_557,
                 -1.312_231_200_509_698e6,
@@ -200,7 +201,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -11.946, Y_-7
 
         rational_7_over_7(
-            -z / (INV_SQRT_E + sqrt(z - NEG_INV_E)),
+            -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E)),
             [
                 -16.841_701_411_264_98,
                 -5.779_082_325_757_714e6,
@@ -226,7 +227,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -41.344, V_-8
 
         rational_7_over_7(
-            ln(-z),
+            K::ln(-z),
             [
                 -2.083_626_038_401_644,
                 1.612_243_624_227_149_6,
@@ -252,7 +253,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // W >= -185.316, V_-9
 
         rational_7_over_7(
-            ln(-z),
+            K::ln(-z),
             [
                 0.160_453_837_665_705_42,
                 2.221_418_252_446_151_4,
@@ -278,7 +279,7 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
         // V_-10
 
         rational_7_over_7(
-            ln(-z),
+            K::ln(-z),
             [
                 -1.274_217_970_307_544,
                 1.369_665_880_542_138_4,
@@ -300,6 +301,9 @@ pub fn dwm1c(z: f64, zc: f64) -> f64 {
                 -1.360_871_393_694_260_3e-23,
             ],
         )
+    } else if z == 0.0 && z.is_sign_negative() {
+        // The limit of the function as the argument approaches 0 from below.
+        f64::NEG_INFINITY
     } else {
         f64::NAN
     }
@@ -1,18 +1,19 @@
 //! This module contains elementary math functions that exist in both the standard library and the [`libm`] crate.
 //! Uses the standard library versions if the `std` feature is enabled, otherwise uses the `libm` versions if the
 //! `std` feature is disabled and the `libm` feature is enabled. If both are disabled these functions panic.
+//! The `deterministic` feature selects the `libm` versions even if the `std` feature is enabled.
 
 // #[inline(always)] is motivated by the fact that these functions are trivial, so just placing the call to the
 // correct sqrt/ln at the caller does not add extra code, but skips an extra indirection.
 
 #[inline(always)]
 pub fn sqrt(x: f64) -> f64 {
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
     {
         x.sqrt()
     }
 
-    #[cfg(all(not(feature = "std"), feature = "libm"))]
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
     {
         libm::sqrt(x)
     }
@@ -25,12 +26,12 @@ pub fn sqrt(x: f64) -> f64 {
 
 #[inline(always)]
 pub fn sqrtf(x: f32) -> f32 {
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
     {
         x.sqrt()
     }
 
-    #[cfg(all(not(feature = "std"), feature = "libm"))]
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
     {
         libm::sqrtf(x)
     }
@@ -43,12 +44,12 @@ pub fn sqrtf(x: f32) -> f32 {
 
 #[inline(always)]
 pub fn ln(x: f64) -> f64 {
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
     {
         x.ln()
     }
 
-    #[cfg(all(not(feature = "std"), feature = "libm"))]
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
     {
         libm::log(x)
     }
@@ -61,12 +62,12 @@ pub fn ln(x: f64) -> f64 {
 
 #[inline(always)]
 pub fn lnf(x: f32) -> f32 {
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
     {
         x.ln()
     }
 
-    #[cfg(all(not(feature = "std"), feature = "libm"))]
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
     {
         libm::logf(x)
     }
@@ -76,3 +77,90 @@ pub fn lnf(x: f32) -> f32 {
         panic!("computing lnf({x}) needs at least one of the `std` or `libm` feature flags to be enabled");
     }
 }
+
+#[inline(always)]
+pub fn exp(x: f64) -> f64 {
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+    {
+        x.exp()
+    }
+
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
+    {
+        libm::exp(x)
+    }
+
+    #[cfg(all(not(feature = "std"), not(feature = "libm")))]
+    {
+        panic!("computing exp({x}) needs at least one of the `std` or `libm` feature flags to be enabled");
+    }
+}
+
+#[inline(always)]
+pub fn ln_1p(x: f64) -> f64 {
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+    {
+        x.ln_1p()
+    }
+
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
+    {
+        libm::log1p(x)
+    }
+
+    #[cfg(all(not(feature = "std"), not(feature = "libm")))]
+    {
+        panic!("computing ln_1p({x}) needs at least one of the `std` or `libm` feature flags to be enabled");
+    }
+}
+
+#[inline(always)]
+pub fn exp_m1(x: f64) -> f64 {
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+    {
+        x.exp_m1()
+    }
+
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
+    {
+        libm::expm1(x)
+    }
+
+    #[cfg(all(not(feature = "std"), not(feature = "libm")))]
+    {
+        panic!("computing exp_m1({x}) needs at least one of the `std` or `libm` feature flags to be enabled");
+    }
+}
+
+#[cfg(feature = "dd")]
+#[inline(always)]
+pub fn mul_add(x: f64, a: f64, b: f64) -> f64 {
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+    {
+        x.mul_add(a, b)
+    }
+
+    #[cfg(all(any(not(feature = "std"), feature = "deterministic"), feature = "libm"))]
+    {
+        libm::fma(x, a, b)
+    }
+
+    #[cfg(all(not(feature = "std"), not(feature = "libm")))]
+    {
+        panic!("computing mul_add({x}, {a}, {b}) needs at least one of the `std` or `libm` feature flags to be enabled");
+    }
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn cos(x: f64) -> f64 {
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.cos()
+    }
+
+    #[cfg(feature = "deterministic")]
+    {
+        libm::cos(x)
+    }
+}
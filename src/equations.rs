@@ -0,0 +1,297 @@
+//! Solvers for equations that can be reduced to the form w·e^w = z with a transformation of the unknown.
+//!
+//! The solvers in this module return every real solution of their equation as a [`RealSolutions`].
+//! They use the principal and secondary branches of the Lambert W function computed to 50 bits of accuracy.
+
+use crate::{
+    elementary::{exp, ln},
+    lambert_w0, lambert_w0_neg_exp, lambert_wm1, NEG_INV_E,
+};
+
+/// The real solutions of an equation.
+///
+/// When there are two solutions the smaller one is stored first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RealSolutions {
+    /// The equation has no real solutions.
+    Zero,
+    /// The equation has exactly one real solution.
+    One(f64),
+    /// The equation has two real solutions, the smaller one first.
+    Two(f64, f64),
+    /// Every real number is a solution of the equation.
+    Infinite,
+}
+
+impl RealSolutions {
+    /// Returns the number of real solutions, or `None` if every real number is a solution.
+    pub const fn count(&self) -> Option<usize> {
+        match self {
+            Self::Zero => Some(0),
+            Self::One(_) => Some(1),
+            Self::Two(_, _) => Some(2),
+            Self::Infinite => None,
+        }
+    }
+
+    /// Creates a [`RealSolutions`] from two solutions in any order.
+    /// If both are the same value only one solution is stored.
+    fn from_pair(x1: f64, x2: f64) -> Self {
+        if x1 == x2 {
+            Self::One(x1)
+        } else if x1 < x2 {
+            Self::Two(x1, x2)
+        } else {
+            Self::Two(x2, x1)
+        }
+    }
+}
+
+/// Solves x·b^x = c for real x.
+///
+/// The solutions are given by W(c·ln(b))/ln(b), where W is either branch of the Lambert W function.
+/// When -1/e < c·ln(b) < 0 both branches give a solution, when c·ln(b) is equal to -1/e they give the same solution,
+/// and when c·ln(b) is non-negative only the principal branch gives a solution.
+///
+/// If b is 1 the equation is x = c, and if c is 0 its only solution is x = 0. If b is not positive, or if any of the inputs is infinite or [`NAN`](f64::NAN),
+/// the equation is considered to have no real solutions.
+/// If c·ln(b) overflows or underflows the Lambert W function is evaluated at it in log space, so the solutions are still found.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::equations::{solve_x_pow, RealSolutions};
+///
+/// // x·2^x = 8 has the single solution x = 2.
+/// match solve_x_pow(8.0, 2.0) {
+///     RealSolutions::One(x) => assert_abs_diff_eq!(x, 2.0),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// Equations with two solutions:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::equations::{solve_x_pow, RealSolutions};
+///
+/// // x·2^x = -1/2 is solved by x = -1 and x = -2.
+/// match solve_x_pow(-0.5, 2.0) {
+///     RealSolutions::Two(x1, x2) => {
+///         assert_abs_diff_eq!(x1, -2.0, epsilon = 1e-14);
+///         assert_abs_diff_eq!(x2, -1.0, epsilon = 1e-14);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// Equations without real solutions:
+///
+/// ```
+/// use lambert_w::equations::{solve_x_pow, RealSolutions};
+///
+/// assert_eq!(solve_x_pow(-1.0, 2.0), RealSolutions::Zero);
+/// assert_eq!(solve_x_pow(1.0, -2.0), RealSolutions::Zero);
+/// ```
+pub fn solve_x_pow(c: f64, b: f64) -> RealSolutions {
+    if !c.is_finite() || !b.is_finite() || b <= 0.0 {
+        return RealSolutions::Zero;
+    }
+
+    if b == 1.0 || c == 0.0 {
+        return RealSolutions::One(c);
+    }
+
+    let ln_b = ln(b);
+    let z = c * ln_b;
+
+    if !z.is_normal() {
+        // c·ln(b) overflows or underflows, so the Lambert W function is evaluated at it in log space.
+        let l = ln(c.abs()) + ln(ln_b.abs());
+        return if (c > 0.0) == (ln_b > 0.0) {
+            RealSolutions::One(lambert_w0_exp(l) / ln_b)
+        } else if l > 0.0 {
+            // c·ln(b) is below -1/e.
+            RealSolutions::Zero
+        } else {
+            // W0(z) = z to within rounding when z is this close to 0, so its solution is c.
+            RealSolutions::from_pair(c, lambert_wm1_neg_exp(-l) / ln_b)
+        };
+    }
+
+    if z < NEG_INV_E {
+        RealSolutions::Zero
+    } else if z == NEG_INV_E {
+        // Both branches meet at -1 here.
+        RealSolutions::One(-1.0 / ln_b)
+    } else if z < 0.0 {
+        RealSolutions::from_pair(lambert_w0(z) / ln_b, lambert_wm1(z) / ln_b)
+    } else {
+        RealSolutions::One(lambert_w0(z) / ln_b)
+    }
+}
+
+/// Solves a·e^(b·x) = c·x + d for real x.
+///
+/// With the substitution u = -b·(x + d/c) the equation becomes u·e^u = -(a·b/c)·e^(-b·d/c),
+/// so the solutions are given by x = -W(-(a·b/c)·e^(-b·d/c))/b - d/c, where W is either branch of the Lambert W function.
+///
+/// If a or b is zero the equation is linear, and if c is zero it is solved with a logarithm.
+/// If the equation degenerates to an identity, such as 0 = 0, [`RealSolutions::Infinite`] is returned.
+/// If any of the inputs is infinite or [`NAN`](f64::NAN) the equation is considered to have no real solutions.
+///
+/// If -(a·b/c)·e^(-b·d/c) overflows or underflows the Lambert W function is evaluated at it in log space,
+/// and the solutions are computed as ln(W/(-a·b/c))/b, which avoids the cancellation in the formula above.
+/// Only if b·d/c itself overflows is the equation considered to have no real solutions.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::equations::{solve_exp_linear, RealSolutions};
+///
+/// // e^x = -x has one solution: x = -W0(1) = -Ω.
+/// match solve_exp_linear(1.0, 1.0, -1.0, 0.0) {
+///     RealSolutions::One(x) => assert_abs_diff_eq!(x, -lambert_w::OMEGA),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// Equations with two solutions:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::equations::{solve_exp_linear, RealSolutions};
+///
+/// // 2^x = 2x, or e^(ln(2)·x) = 2x, is solved by x = 1 and x = 2.
+/// match solve_exp_linear(1.0, f64::ln(2.0), 2.0, 0.0) {
+///     RealSolutions::Two(x1, x2) => {
+///         assert_abs_diff_eq!(x1, 1.0, epsilon = 1e-14);
+///         assert_abs_diff_eq!(x2, 2.0, epsilon = 1e-14);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// Degenerate equations:
+///
+/// ```
+/// use lambert_w::equations::{solve_exp_linear, RealSolutions};
+///
+/// // e^x = 0 has no solution.
+/// assert_eq!(solve_exp_linear(1.0, 1.0, 0.0, 0.0), RealSolutions::Zero);
+/// // 0·e^x = 2x - 4 is solved by x = 2.
+/// assert_eq!(solve_exp_linear(0.0, 1.0, 2.0, -4.0), RealSolutions::One(2.0));
+/// // 3·e^(0·x) = 0·x + 3 is solved by every x.
+/// assert_eq!(solve_exp_linear(3.0, 0.0, 0.0, 3.0), RealSolutions::Infinite);
+/// ```
+pub fn solve_exp_linear(a: f64, b: f64, c: f64, d: f64) -> RealSolutions {
+    if !(a.is_finite() && b.is_finite() && c.is_finite() && d.is_finite()) {
+        return RealSolutions::Zero;
+    }
+
+    if a == 0.0 || b == 0.0 {
+        // The left hand side is the constant a·e^0 or 0, so the equation is linear.
+        let lhs = if a == 0.0 { 0.0 } else { a };
+        return if c != 0.0 {
+            RealSolutions::One((lhs - d) / c)
+        } else if lhs == d {
+            RealSolutions::Infinite
+        } else {
+            RealSolutions::Zero
+        };
+    }
+
+    if c == 0.0 {
+        // a·e^(b·x) = d
+        let ratio = d / a;
+        return if ratio > 0.0 {
+            RealSolutions::One(ln(ratio) / b)
+        } else {
+            RealSolutions::Zero
+        };
+    }
+
+    let shift = d / c;
+    let t = -b * shift;
+    let k = -(a * b / c);
+    let z = k * exp(t);
+
+    if !(z.is_normal() && k.is_normal()) {
+        // z = k·e^t overflows or underflows, so the Lambert W function is evaluated at it in log space.
+        let ln_k = ln(a.abs()) + ln(b.abs()) - ln(c.abs());
+        let l = ln_k + t;
+        if !l.is_finite() {
+            // b·d/c overflows, and this does not compute the solutions from it.
+            return RealSolutions::Zero;
+        }
+        // From W·e^W = k·e^t it follows that W - t = ln(k/W), so x = -W/b - d/c = ln(W/k)/b.
+        // Unlike the formula with d/c this has no cancellation when t is large.
+        // W and k have the same sign, and x tends to -d/c as W tends to 0.
+        let x = |w: f64| {
+            if w == 0.0 {
+                -shift
+            } else {
+                (ln(w.abs()) - ln_k) / b
+            }
+        };
+        let k_is_positive = (a > 0.0) != ((b > 0.0) == (c > 0.0));
+        return if k_is_positive {
+            RealSolutions::One(x(lambert_w0_exp(l)))
+        } else if l > -1.0 {
+            // z is below -1/e.
+            RealSolutions::Zero
+        } else if l == -1.0 {
+            RealSolutions::One(x(-1.0))
+        } else {
+            RealSolutions::from_pair(x(lambert_w0_neg_exp(-l)), x(lambert_wm1_neg_exp(-l)))
+        };
+    }
+
+    if z < NEG_INV_E {
+        RealSolutions::Zero
+    } else if z == NEG_INV_E {
+        // Both branches meet at -1 here.
+        RealSolutions::One(1.0 / b - shift)
+    } else if z < 0.0 {
+        RealSolutions::from_pair(-lambert_w0(z) / b - shift, -lambert_wm1(z) / b - shift)
+    } else {
+        RealSolutions::One(-lambert_w0(z) / b - shift)
+    }
+}
+
+/// Computes W0(e^l), also when e^l overflows.
+///
+/// There the asymptotic expansion W0(e^l) ≈ l - ln(l) + ln(l)/l is refined with Newton's method on w + ln(w) = l.
+fn lambert_w0_exp(l: f64) -> f64 {
+    let z = exp(l);
+    if z < f64::INFINITY {
+        return lambert_w0(z);
+    }
+    let ln_l = ln(l);
+    let mut w = l - ln_l + ln_l / l;
+    for _ in 0..3 {
+        w -= (w + ln(w) - l) / (1.0 + 1.0 / w);
+    }
+    w
+}
+
+/// Computes W-1(-e^(-y)) for y > 1, also when e^(-y) underflows.
+///
+/// There v = -W-1 solves v - ln(v) = y, and the asymptotic expansion v ≈ y + ln(y) is refined with Newton's method on that equation.
+fn lambert_wm1_neg_exp(y: f64) -> f64 {
+    let z = -exp(-y);
+    if z.is_normal() {
+        return lambert_wm1(z);
+    }
+    let mut v = y + ln(y);
+    for _ in 0..3 {
+        v -= (v - ln(v) - y) / (1.0 - 1.0 / v);
+    }
+    -v
+}
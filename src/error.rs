@@ -0,0 +1,44 @@
+//! The error types of the fallible functions of the crate.
+
+use core::fmt;
+
+/// The error returned by functions that can not represent an invalid result with NaN,
+/// when they are given an argument that is outside the domain of the function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DomainError;
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the argument is outside the domain of the function")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DomainError {}
+
+/// The error returned when converting an index that does not refer to a real branch of the Lambert W function
+/// to a [`Branch`](crate::Branch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BranchIndexError {
+    pub(crate) index: i64,
+}
+
+impl BranchIndexError {
+    /// Returns the index that could not be converted.
+    pub const fn index(&self) -> i64 {
+        self.index
+    }
+}
+
+impl fmt::Display for BranchIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the branch with index {} does not have real values, only the branches with index 0 and -1 do",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BranchIndexError {}
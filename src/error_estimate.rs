@@ -0,0 +1,62 @@
+use crate::{
+    elementary::{exp, sqrt},
+    lambert_w0, NEG_INV_E,
+};
+
+// This file contains an evaluation of the principal branch of the Lambert W function
+// together with an empirical estimate of its absolute error.
+// The estimate is not a rigorous bound: it is not derived from the minimax errors of the fits or from
+// a rounding error analysis of their evaluation, but from testing against arbitrary precision evaluations.
+// It is the sum of three terms:
+// the error of the rational approximations, which is estimated as REL_ERROR_ESTIMATE relative to the result,
+// the rounding error in the evaluation of the rational functions, which is absolute close to zero where the
+// result is computed as a difference of numbers close to 1, and the error in the offset z + 1/e
+// that the approximations take as their input, propagated through the derivative of the function.
+// The offset is computed with a compensated -1/e, so its error is a single rounding, except at NEG_INV_E,
+// which the crate treats as the branch point even though it differs slightly from -1/e.
+
+/// Estimates the relative error of the rational approximations on every subdomain.
+/// Fukushima reports 50 bits of accuracy, i.e. 2^-50 ≈ 8.9e-16, and testing against
+/// arbitrary precision evaluations finds at most 8.8e-16. This is twice the reported error, as a margin.
+const REL_ERROR_ESTIMATE: f64 = 1.776_356_839_400_250_5e-15;
+
+/// Estimates the absolute rounding error in the evaluation of the rational functions close to zero.
+/// Testing finds at most 1.4e-16.
+const ABS_ERROR_ESTIMATE: f64 = f64::EPSILON;
+
+/// Bounds the difference between [`NEG_INV_E`] and -1/e, which is the error in the offset at [`NEG_INV_E`].
+const NEG_INV_E_ERROR: f64 = 1.25e-17;
+
+pub fn w0_with_error_estimate(z: f64) -> (f64, f64) {
+    let w = lambert_w0(z);
+    if w.is_nan() {
+        return (w, f64::NAN);
+    } else if z == 0.0 || w == f64::INFINITY {
+        return (w, 0.0);
+    }
+
+    let zc = z - NEG_INV_E;
+    let zc_error = if z == NEG_INV_E {
+        NEG_INV_E_ERROR
+    } else {
+        f64::EPSILON * zc
+    };
+
+    // The function is proportional to sqrt(zc) close to the branch point, with W0(z) + 1 = p - p^2/3 + ..., where p = sqrt(2e·zc).
+    // Since W0 + 1 grows slower than p, the error in p bounds the propagated error there,
+    // and it is at most sqrt(2e·zc_error) for any zc.
+    let wp1 = w + 1.0;
+    let near_branch_error = sqrt(2.0 * core::f64::consts::E * zc_error);
+    let propagated_error = if wp1 > 0.0 {
+        // W0'(z) = 1/(e^w·(1 + w))
+        let derivative = exp(-w) / wp1;
+        (2.0 * derivative * zc_error).min(near_branch_error)
+    } else {
+        near_branch_error
+    };
+
+    (
+        w,
+        REL_ERROR_ESTIMATE * w.abs() + ABS_ERROR_ESTIMATE + propagated_error,
+    )
+}
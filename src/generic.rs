@@ -0,0 +1,48 @@
+//! Evaluation of the branches of the Lambert W function on any type that implements [`Float`].
+
+use num_traits::Float;
+
+use crate::{lambert_w0, lambert_wm1};
+
+// The functions in this file compute an initial approximation with the 50 bit approximation
+// on f64, and then perform one step of Halley's method in the arithmetic of the generic type.
+// Since Halley's method has cubic convergence, this gives close to the full precision of any type with
+// less than about 150 bits of precision, while all arithmetic that depends on the exact input is done in that type.
+
+pub fn w0_generic<T: Float>(z: T) -> T {
+    refine(z, lambert_w0)
+}
+
+pub fn wm1_generic<T: Float>(z: T) -> T {
+    refine(z, lambert_wm1)
+}
+
+fn refine<T: Float>(z: T, f: fn(f64) -> f64) -> T {
+    let w = match z.to_f64().map(f).and_then(T::from) {
+        Some(w) => w,
+        None => return T::nan(),
+    };
+
+    let one = T::one();
+    let two = one + one;
+    let wp1 = w + one;
+
+    // Halley's method divides by w + 1, so it can not be used at the branch point,
+    // and the step can overflow for function values that are at the limits of the type.
+    if !w.is_finite() || wp1 == T::zero() {
+        return w;
+    }
+
+    let ew = w.exp();
+    let f = w * ew - z;
+    if f == T::zero() {
+        return w;
+    }
+
+    let refined = w - f / (ew * wp1 - (w + two) * f / (two * wp1));
+    if refined.is_finite() {
+        refined
+    } else {
+        w
+    }
+}
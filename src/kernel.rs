@@ -0,0 +1,59 @@
+//! The elementary functions that the 50-bit approximations need, as a trait that users can implement.
+
+use crate::elementary::{ln, sqrt};
+
+/// The elementary functions that [`lambert_w0_with_kernel`](crate::lambert_w0_with_kernel)
+/// and [`lambert_wm1_with_kernel`](crate::lambert_wm1_with_kernel) evaluate.
+///
+/// Implement this trait to use for example vendor-optimized, table-based, or fixed-latency implementations
+/// of the square root and the natural logarithm instead of those from the standard library or [`libm`].
+/// The accuracy of the result depends on the accuracy of the functions: with correctly rounded
+/// implementations the result has the same accuracy as [`lambert_w0`](crate::lambert_w0) and [`lambert_wm1`](crate::lambert_wm1).
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_with_kernel, MathKernel};
+///
+/// struct MyKernel;
+///
+/// impl MathKernel for MyKernel {
+///     fn sqrt(x: f64) -> f64 {
+///         x.sqrt()
+///     }
+///
+///     fn ln(x: f64) -> f64 {
+///         x.ln()
+///     }
+/// }
+///
+/// assert_eq!(lambert_w0_with_kernel::<MyKernel>(2.0), lambert_w0(2.0));
+/// ```
+pub trait MathKernel {
+    /// Returns the square root of `x`.
+    ///
+    /// Is only called with non-negative arguments.
+    fn sqrt(x: f64) -> f64;
+
+    /// Returns the natural logarithm of `x`.
+    ///
+    /// Is only called with positive and finite arguments.
+    fn ln(x: f64) -> f64;
+}
+
+/// The kernel that the functions of the crate use, which evaluates the functions from the standard library
+/// if the `std` feature is enabled and from [`libm`] otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DefaultKernel;
+
+impl MathKernel for DefaultKernel {
+    #[inline(always)]
+    fn sqrt(x: f64) -> f64 {
+        sqrt(x)
+    }
+
+    #[inline(always)]
+    fn ln(x: f64) -> f64 {
+        ln(x)
+    }
+}
@@ -87,6 +87,15 @@
 //! assert_abs_diff_eq!(z.lambert_w0(), f64::ln(2.0));
 //! ```
 //!
+//! The trait is also implemented for `i32`, `u32`, `i64` and `u64`, which are converted to `f64`s:
+//!
+//! ```
+//! # use lambert_w::LambertW;
+//! use lambert_w::lambert_w0;
+//!
+//! assert_eq!(1_000_000_u64.lambert_w0(), lambert_w0(1e6));
+//! ```
+//!
 //! The macros are from the [`approx`](https://docs.rs/approx/latest/approx/) crate, and are used in the documentation examples of this crate.
 //! The assertion passes if the two supplied values are the same to within floating point error, or within an optional epsilon or relative difference.
 //!
@@ -98,8 +107,52 @@
 //! crate to compute square roots and logarithms during function evaluation instead of the standard library.
 //!
 //! `std`: use the standard library to compute square roots and logarithms for a potential performance gain.
+//! Also enables the `approx_builder` module, which constructs approximations tailored to a narrow input interval at runtime,
+//! and the `W0Table` type, which interpolates the principal branch from a precomputed table.
 //! When this feature is disabled the crate is `no_std` compatible.
 //!
+//! The below features are optional:
+//!
+//! `deterministic`: computes square roots, logarithms and exponentials with the [`libm`] crate even if the `std` feature is enabled.
+//! Since `libm` is implemented in Rust and the basic arithmetic operations are correctly rounded, this makes the results of the functions
+//! in this crate bit-identical on all platforms, regardless of the math library of the platform. This may cost some performance.
+//!
+//! `num-traits`: enables the `lambert_w0_generic` and `lambert_wm1_generic` functions that work on any type
+//! that implements the `Float` trait from the [`num-traits`](https://docs.rs/num-traits/latest/num_traits/) crate.
+//!
+//! `num-dual`: enables the `lambert_w0_dual` and `lambert_wm1_dual` functions, and implementations of the [`LambertW`] trait for `Dual64`, `Dual2_64` and `HyperDual64`,
+//! that propagate derivatives through the function with the dual numbers of the [`num-dual`](https://docs.rs/num-dual/latest/num_dual/) crate.
+//!
+//! `ordered-float`: implements the [`LambertW`] trait for the `OrderedFloat<f64>` and `NotNan<f64>` types
+//! from the [`ordered-float`](https://docs.rs/ordered-float/latest/ordered_float/) crate.
+//! The functions on `NotNan<f64>` return a `Result` with a [`DomainError`] when the result would be NaN.
+//!
+//! `simba`: enables the `lambert_w0_simd` and `lambert_wm1_simd` functions that work on any type that implements the `SimdRealField` trait
+//! from the [`simba`](https://docs.rs/simba/latest/simba/) crate with `f64` lanes, e.g. the SIMD types used by [`nalgebra`](https://docs.rs/nalgebra/latest/nalgebra/),
+//! and the `lambert_w0f_simd` and `lambert_wm1f_simd` functions that work on such types with `f32` lanes.
+//!
+//! `half`: enables the `lambert_w0_f16` and `lambert_wm1_f16` functions, and an implementation of the [`LambertW`] trait,
+//! for the `f16` type from the [`half`](https://docs.rs/half/latest/half/) crate.
+//!
+//! `dd`: enables the `lambert_w0_dd` and `lambert_wm1_dd` functions that compute the function
+//! to about 100 bits of accuracy in double-double arithmetic.
+//!
+//! `rust_decimal`: enables the `lambert_w0_decimal` function that evaluates the principal branch
+//! on the `Decimal` type from the [`rust_decimal`](https://docs.rs/rust_decimal/latest/rust_decimal/) crate.
+//!
+//! `serde`: implements `Serialize` and `Deserialize` from the [`serde`](https://docs.rs/serde/latest/serde/) crate
+//! for the [`Branch`] type.
+//!
+//! ## Finding invalid arguments
+//!
+//! When the crate is compiled with `RUSTFLAGS="--cfg lambert_w_strict_debug"`, the functions `lambert_w0`, `lambert_wm1`,
+//! `lambert_w0f`, `lambert_wm1f`, `sp_lambert_w0` and `sp_lambert_wm1`, and the functions that are built on them,
+//! panic in builds with debug assertions when they are given an argument outside their domain, instead of returning NaN.
+//! The panic points to the line of the call, which finds where an invalid argument first entered a long computation.
+//! NaN arguments are still passed on, and release builds are unaffected.
+//! This is a `cfg` and not a feature, so that it can only be enabled by whoever builds the final program,
+//! and not by a dependency of a crate that relies on getting NaN.
+//!
 //! ## References
 //!
 //! \[1\]: Toshio Fukushima.
@@ -118,37 +171,67 @@ extern crate std;
 #[cfg(not(any(feature = "std", feature = "libm")))]
 compile_error!("at least one of the `std` or `libm` features must be enabled");
 
+mod accuracy;
+#[cfg(feature = "std")]
+pub mod approx_builder;
+mod branch;
+pub mod consts;
+#[cfg(feature = "dd")]
+mod dd;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "num-dual")]
+mod dual;
 mod dw0c;
 mod dwm1c;
 mod elementary;
+pub mod equations;
+mod error;
+mod error_estimate;
+#[cfg(feature = "num-traits")]
+mod generic;
+mod kernel;
+mod linspace;
+mod neg_exp;
+mod policy;
+mod puiseux;
+mod quick;
 mod rational;
+mod refine;
+mod roundtrip;
+mod seam;
+mod segment_id;
+#[cfg(feature = "simba")]
+mod simd_real;
+mod solver;
+#[cfg(lambert_w_strict_debug)]
+mod strict;
 mod sw0;
 mod sw0f;
 mod swm1;
 mod swm1f;
+#[cfg(feature = "std")]
+mod table;
+mod uncertain;
+mod uniform;
 #[cfg(test)]
 mod unit_tests;
+mod verified;
 
-/// The negative inverse of e (-1/e).
-///
-/// This is the smallest input value for which the Lambert W functions in this crate return a value.
-//            Rounded from -0.367_879_441_171_442_322
-pub const NEG_INV_E: f64 = -0.367_879_441_171_442_32;
-
-/// 1/sqrt(e)
-//         Rounded from 0.606_530_659_712_633_423
-const INV_SQRT_E: f64 = 0.606_530_659_712_633_4;
-
-/// The omega constant (Ω).
-///
-/// Fulfills the equation Ωe^Ω = 1.
-///
-/// Has been rounded to the closest available `f64` value.
-//        Rounded from 0.567_143_290_409_783_87
-pub const OMEGA: f64 = 0.567_143_290_409_783_8;
-// If we round the last two digits (87) to 9 rustc sets the constant to
-//                     0.567_143_290_409_784
-// which is further away from the true value than what we get if we round them to 8.
+pub use accuracy::{Accuracy, Accuracy24, Accuracy50, AccuracyTier};
+pub use branch::Branch;
+pub use consts::{NEG_INV_E, OMEGA};
+#[cfg(feature = "dd")]
+pub use dd::DoubleDouble;
+pub use error::{BranchIndexError, DomainError};
+pub use kernel::{DefaultKernel, MathKernel};
+pub use linspace::W0Linspace;
+pub use policy::{ClampPolicy, DomainPolicy, ErrorPolicy, NanPolicy};
+pub use segment_id::SegmentId;
+pub use solver::LambertWSolver;
+#[cfg(feature = "std")]
+pub use table::W0Table;
+pub use verified::Verified;
 
 /// The principal branch of the Lambert W function computed to 24 bits of accuracy on `f64`s.
 ///
@@ -175,8 +258,13 @@ pub const OMEGA: f64 = 0.567_143_290_409_783_8;
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn sp_lambert_w0(z: f64) -> f64 {
-    sw0::sw0(z)
+    // The approximation is not exactly zero at zero, and would lose the sign of a negative zero.
+    let w = if z == 0.0 { z } else { sw0::sw0(z) };
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("sp_lambert_w0", z, !w.is_nan() || z.is_nan());
+    w
 }
 
 /// The secondary branch of the Lambert W function computed to 24 bits of accuracy on `f64`s.
@@ -205,12 +293,19 @@ pub fn sp_lambert_w0(z: f64) -> f64 {
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn sp_lambert_wm1(z: f64) -> f64 {
-    swm1::swm1(z)
+    let w = swm1::swm1(z);
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("sp_lambert_wm1", z, !w.is_nan() || z.is_nan());
+    w
 }
 
 /// The principal branch of the Lambert W function computed to 50 bits of accuracy.
 ///
+/// The approximation only has absolute accuracy close to 0, so for tiny arguments the Taylor series around 0 is used instead.
+/// Use [`lambert_w0_with_accuracy`] with [`Accuracy::Full`] for full relative accuracy also for the other arguments close to 0.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -231,11 +326,34 @@ pub fn sp_lambert_wm1(z: f64) -> f64 {
 /// assert!(lambert_w0(-1.0).is_nan());
 /// ```
 ///
+/// The inputs 0, 1, e and -1/e give the exactly representable function values
+/// 0, [`OMEGA`], 1 and -1 respectively:
+///
+/// ```
+/// use lambert_w::{lambert_w0, NEG_INV_E, OMEGA};
+///
+/// assert_eq!(lambert_w0(0.0), 0.0);
+/// assert_eq!(lambert_w0(1.0), OMEGA);
+/// assert_eq!(lambert_w0(core::f64::consts::E), 1.0);
+/// assert_eq!(lambert_w0(NEG_INV_E), -1.0);
+/// ```
+///
+/// The sign of a zero argument is preserved:
+///
+/// ```
+/// # use lambert_w::lambert_w0;
+/// assert!(lambert_w0(-0.0).is_sign_negative());
+/// ```
+///
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn lambert_w0(z: f64) -> f64 {
-    dw0c::dw0c(z - NEG_INV_E)
+    let w = lambert_w0_with_kernel::<DefaultKernel>(z);
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("lambert_w0", z, !w.is_nan() || z.is_nan());
+    w
 }
 
 /// The principal branch of the Lambert W function, computed with `f32`s.
@@ -244,6 +362,9 @@ pub fn lambert_w0(z: f64) -> f64 {
 /// which may result in slightly reduced accuracy.
 /// This potential accuracy reduction has not been quantified.
 ///
+/// The computation only uses `f32` arithmetic, so targets whose floating point unit only supports single precision
+/// do not need to emulate `f64` operations in software.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -264,11 +385,110 @@ pub fn lambert_w0(z: f64) -> f64 {
 /// assert!(lambert_w0f(-1.0).is_nan());
 /// ```
 ///
+/// The inputs 0, 1, e and -1/e give the exactly representable function values
+/// 0, [`OMEGA_F32`](consts::OMEGA_F32), 1 and -1 respectively:
+///
+/// ```
+/// use lambert_w::{consts::{NEG_INV_E_F32, OMEGA_F32}, lambert_w0f};
+///
+/// assert_eq!(lambert_w0f(0.0), 0.0);
+/// assert_eq!(lambert_w0f(1.0), OMEGA_F32);
+/// assert_eq!(lambert_w0f(core::f32::consts::E), 1.0);
+/// assert_eq!(lambert_w0f(NEG_INV_E_F32), -1.0);
+/// ```
+///
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn lambert_w0f(z: f32) -> f32 {
-    sw0f::sw0f(z)
+    // Return the closest representable value at inputs where it is known exactly.
+    let w = if z == 0.0 {
+        z
+    } else if z == 1.0 {
+        consts::OMEGA_F32
+    } else if z == core::f32::consts::E {
+        1.0
+    } else if z == consts::NEG_INV_E_F32 {
+        -1.0
+    } else {
+        sw0f::sw0f(z)
+    };
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("lambert_w0f", z, !w.is_nan() || z.is_nan());
+    w
+}
+
+/// The principal branch of the Lambert W function, computed with `f64`s and rounded once to an `f32`.
+///
+/// Uses [`lambert_w0`] on the argument converted to an `f64`, which is exact, and rounds the result
+/// to the nearest `f32`. Since the 50 bits of accuracy of the `f64` approximation are far more than the 24 bits of an `f32`,
+/// the result is the correctly rounded function value for all but a vanishingly small fraction of arguments.
+/// This is slower than [`lambert_w0f`], but more accurate.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{consts::OMEGA_F32, lambert_w0f_precise};
+///
+/// assert_eq!(lambert_w0f_precise(1.0), OMEGA_F32);
+/// assert_eq!(lambert_w0f_precise(3.0), 1.049_908_9);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944) result in [`NAN`](f32::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0f_precise;
+/// assert!(lambert_w0f_precise(-1.0).is_nan());
+/// ```
+pub fn lambert_w0f_precise(z: f32) -> f32 {
+    // The closest f32 to -1/e is slightly smaller than -1/e. Treat it as the branch point,
+    // like `lambert_w0f` does.
+    let z = f64::from(z);
+    if z == f64::from(consts::NEG_INV_E_F32) {
+        -1.0
+    } else if z.abs() < 1e-4 {
+        // The approximation has an absolute rather than relative error close to zero,
+        // so the Taylor series W0(z) = z - z^2 + 3/2·z^3 - 8/3·z^4 + ... is used there instead.
+        (z * (1.0 + z * (-1.0 + z * (1.5 - 8.0 / 3.0 * z)))) as f32
+    } else {
+        lambert_w0(z) as f32
+    }
+}
+
+/// The secondary branch of the Lambert W function, computed with `f64`s and rounded once to an `f32`.
+///
+/// Uses [`lambert_wm1`] on the argument converted to an `f64`, which is exact, and rounds the result
+/// to the nearest `f32`. Since the 50 bits of accuracy of the `f64` approximation are far more than the 24 bits of an `f32`,
+/// the result is the correctly rounded function value for all but a vanishingly small fraction of arguments.
+/// This is slower than [`lambert_wm1f`], but more accurate, also for subnormal arguments.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::lambert_wm1f_precise;
+///
+/// assert_eq!(lambert_wm1f_precise(-0.3), -1.781_336_9);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944) or larger than 0 result in [`NAN`](f32::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1f_precise;
+/// assert!(lambert_wm1f_precise(-1.0).is_nan());
+/// assert!(lambert_wm1f_precise(1.0).is_nan());
+/// ```
+pub fn lambert_wm1f_precise(z: f32) -> f32 {
+    // The closest f32 to -1/e is slightly smaller than -1/e. Treat it as the branch point.
+    if z == consts::NEG_INV_E_F32 {
+        -1.0
+    } else {
+        lambert_wm1(f64::from(z)) as f32
+    }
 }
 
 /// The secondary branch of the Lambert W function computed to 50 bits of accuracy.
@@ -294,11 +514,317 @@ pub fn lambert_w0f(z: f32) -> f32 {
 /// assert!(lambert_wm1(1.0).is_nan());
 /// ```
 ///
+/// The function tends to negative infinity as the argument approaches 0 from below,
+/// so -0.0 results in [`NEG_INFINITY`](f64::NEG_INFINITY) while 0.0 results in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1;
+/// assert_eq!(lambert_wm1(-0.0), f64::NEG_INFINITY);
+/// assert!(lambert_wm1(0.0).is_nan());
+/// ```
+///
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn lambert_wm1(z: f64) -> f64 {
-    dwm1c::dwm1c(z, z - NEG_INV_E)
+    let w = lambert_wm1_with_kernel::<DefaultKernel>(z);
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("lambert_wm1", z, !w.is_nan() || z.is_nan());
+    w
+}
+
+/// The branch `branch` of the Lambert W function computed to 50 bits of accuracy.
+///
+/// Lets code select the branch at runtime, and gives the same results as [`lambert_w0`] and [`lambert_wm1`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w, Branch, OMEGA};
+///
+/// assert_eq!(lambert_w(Branch::W0, 1.0), OMEGA);
+/// assert_eq!(lambert_w(Branch::Wm1, -f64::ln(2.0) / 2.0), -f64::ln(4.0));
+/// ```
+///
+/// Arguments outside the domain of the branch result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_w, Branch};
+/// assert!(lambert_w(Branch::W0, -1.0).is_nan());
+/// assert!(lambert_w(Branch::Wm1, 1.0).is_nan());
+/// ```
+pub fn lambert_w(branch: Branch, z: f64) -> f64 {
+    match branch {
+        Branch::W0 => lambert_w0(z),
+        Branch::Wm1 => lambert_wm1(z),
+    }
+}
+
+/// The principal branch of the Lambert W function computed to the accuracy of the tier `A`, which is chosen at compile time.
+///
+/// Gives the same results as [`sp_lambert_w0`] for [`Accuracy24`] and as [`lambert_w0`] for [`Accuracy50`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_with, Accuracy24, Accuracy50, OMEGA};
+///
+/// assert_eq!(lambert_w0_with::<Accuracy50>(1.0), OMEGA);
+/// assert!((lambert_w0_with::<Accuracy24>(1.0) - OMEGA).abs() < 1e-7);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_w0_with, Accuracy50};
+/// assert!(lambert_w0_with::<Accuracy50>(-1.0).is_nan());
+/// ```
+pub fn lambert_w0_with<A: AccuracyTier>(z: f64) -> f64 {
+    A::lambert_w0(z)
+}
+
+/// The secondary branch of the Lambert W function computed to the accuracy of the tier `A`, which is chosen at compile time.
+///
+/// Gives the same results as [`sp_lambert_wm1`] for [`Accuracy24`] and as [`lambert_wm1`] for [`Accuracy50`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1, lambert_wm1_with, Accuracy50};
+///
+/// assert_eq!(lambert_wm1_with::<Accuracy50>(-0.2), lambert_wm1(-0.2));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_wm1_with, Accuracy50};
+/// assert!(lambert_wm1_with::<Accuracy50>(1.0).is_nan());
+/// ```
+pub fn lambert_wm1_with<A: AccuracyTier>(z: f64) -> f64 {
+    A::lambert_wm1(z)
+}
+
+/// The principal branch of the Lambert W function computed to an accuracy that is chosen at runtime,
+/// e.g. from a setting of the application.
+///
+/// [`Accuracy::Bits24`] uses [`sp_lambert_w0`], [`Accuracy::Bits50`] uses [`lambert_w0`],
+/// and [`Accuracy::Full`] refines the result of [`lambert_w0`] with one iteration by Fritsch, Shafer and Crowley.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_with_accuracy, Accuracy, OMEGA};
+///
+/// assert_relative_eq!(lambert_w0_with_accuracy(1.0, Accuracy::Bits24), OMEGA, max_relative = 1e-7);
+/// assert_eq!(lambert_w0_with_accuracy(1.0, Accuracy::Full), OMEGA);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_w0_with_accuracy, Accuracy};
+/// assert!(lambert_w0_with_accuracy(-1.0, Accuracy::Full).is_nan());
+/// ```
+pub fn lambert_w0_with_accuracy(z: f64, accuracy: Accuracy) -> f64 {
+    match accuracy {
+        Accuracy::Bits24 => sp_lambert_w0(z),
+        Accuracy::Bits50 => lambert_w0(z),
+        Accuracy::Full => refine::fritsch_checked(z, lambert_w0(z)),
+    }
+}
+
+/// The secondary branch of the Lambert W function computed to an accuracy that is chosen at runtime,
+/// e.g. from a setting of the application.
+///
+/// [`Accuracy::Bits24`] uses [`sp_lambert_wm1`], [`Accuracy::Bits50`] uses [`lambert_wm1`],
+/// and [`Accuracy::Full`] refines the result of [`lambert_wm1`] with one iteration by Fritsch, Shafer and Crowley.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_wm1, lambert_wm1_with_accuracy, Accuracy};
+///
+/// assert_relative_eq!(lambert_wm1_with_accuracy(-0.2, Accuracy::Bits24), lambert_wm1(-0.2), max_relative = 1e-7);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_wm1_with_accuracy, Accuracy};
+/// assert!(lambert_wm1_with_accuracy(1.0, Accuracy::Full).is_nan());
+/// ```
+pub fn lambert_wm1_with_accuracy(z: f64, accuracy: Accuracy) -> f64 {
+    match accuracy {
+        Accuracy::Bits24 => sp_lambert_wm1(z),
+        Accuracy::Bits50 => lambert_wm1(z),
+        Accuracy::Full => refine::fritsch_checked(z, lambert_wm1(z)),
+    }
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// with arguments outside its domain handled according to the policy `P`.
+///
+/// Gives the same results as [`lambert_w0`] for [`NanPolicy`].
+/// With [`ErrorPolicy`] it returns a [`DomainError`] instead of [`NAN`](f64::NAN),
+/// and with [`ClampPolicy`] arguments smaller than -1/e are clamped to -1/e.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_with_domain_policy, ErrorPolicy, OMEGA};
+///
+/// assert_eq!(lambert_w0_with_domain_policy::<ErrorPolicy>(1.0), Ok(OMEGA));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) are handled by the policy:
+///
+/// ```
+/// # use lambert_w::{lambert_w0_with_domain_policy, ClampPolicy, DomainError, ErrorPolicy, NanPolicy};
+/// assert!(lambert_w0_with_domain_policy::<NanPolicy>(-1.0).is_nan());
+/// assert_eq!(lambert_w0_with_domain_policy::<ErrorPolicy>(-1.0), Err(DomainError));
+/// assert_eq!(lambert_w0_with_domain_policy::<ClampPolicy>(-1.0), -1.0);
+/// ```
+pub fn lambert_w0_with_domain_policy<P: DomainPolicy>(z: f64) -> P::Output {
+    P::eval(lambert_w0, z, NEG_INV_E, f64::INFINITY)
+}
+
+/// The secondary branch of the Lambert W function computed to 50 bits of accuracy,
+/// with arguments outside its domain handled according to the policy `P`.
+///
+/// Gives the same results as [`lambert_wm1`] for [`NanPolicy`].
+/// With [`ErrorPolicy`] it returns a [`DomainError`] instead of [`NAN`](f64::NAN),
+/// and with [`ClampPolicy`] arguments smaller than -1/e are clamped to -1/e
+/// and arguments larger than or equal to 0 to -0.0, where the function is [`NEG_INFINITY`](f64::NEG_INFINITY).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1, lambert_wm1_with_domain_policy, ErrorPolicy};
+///
+/// assert_eq!(lambert_wm1_with_domain_policy::<ErrorPolicy>(-0.2), Ok(lambert_wm1(-0.2)));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 are handled by the policy:
+///
+/// ```
+/// # use lambert_w::{lambert_wm1_with_domain_policy, ClampPolicy, DomainError, ErrorPolicy, NanPolicy};
+/// assert!(lambert_wm1_with_domain_policy::<NanPolicy>(1.0).is_nan());
+/// assert_eq!(lambert_wm1_with_domain_policy::<ErrorPolicy>(1.0), Err(DomainError));
+/// assert_eq!(lambert_wm1_with_domain_policy::<ClampPolicy>(1.0), f64::NEG_INFINITY);
+/// assert_eq!(lambert_wm1_with_domain_policy::<ClampPolicy>(-1.0), -1.0);
+/// ```
+pub fn lambert_wm1_with_domain_policy<P: DomainPolicy>(z: f64) -> P::Output {
+    P::eval(lambert_wm1, z, NEG_INV_E, -0.0)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// with the square roots and logarithms evaluated by the given [`MathKernel`].
+///
+/// Gives the same results as [`lambert_w0`] if the functions of the kernel are correctly rounded,
+/// and lets users choose which implementations of those functions are linked,
+/// e.g. vendor-optimized or table-based ones.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_with_kernel, DefaultKernel, OMEGA};
+///
+/// assert_eq!(lambert_w0_with_kernel::<DefaultKernel>(1.0), OMEGA);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_w0_with_kernel, DefaultKernel};
+/// assert!(lambert_w0_with_kernel::<DefaultKernel>(-1.0).is_nan());
+/// ```
+pub fn lambert_w0_with_kernel<K: MathKernel>(z: f64) -> f64 {
+    w0_with(z, dw0c::dw0c::<K>)
+}
+
+/// Computes the principal branch with the given approximation of it as a function of zc = z + 1/e,
+/// handling the arguments where the value is known exactly and the arguments close to 0.
+#[inline(always)]
+fn w0_with(z: f64, dw0c: impl Fn(f64) -> f64) -> f64 {
+    // Return the closest representable value at inputs where it is known exactly.
+    if z == 0.0 {
+        z
+    } else if z == 1.0 {
+        OMEGA
+    } else if z == core::f64::consts::E {
+        1.0
+    } else if z == NEG_INV_E {
+        -1.0
+    } else if z.abs() < W0_SERIES_LIMIT {
+        // The first omitted term of the Taylor series z - z^2 + 3z^3/2 - ... is smaller than half an ulp of the result.
+        z * (1.0 - z)
+    } else {
+        dw0c(offset_from_branch_point(z))
+    }
+}
+
+/// Below this magnitude of the argument [`lambert_w0_with_kernel`] evaluates the Taylor series of the function around 0.
+const W0_SERIES_LIMIT: f64 = 7.450_580_596_923_828e-9; // 2^-27
+
+/// The secondary branch of the Lambert W function computed to 50 bits of accuracy,
+/// with the square roots and logarithms evaluated by the given [`MathKernel`].
+///
+/// Gives the same results as [`lambert_wm1`] if the functions of the kernel are correctly rounded,
+/// and lets users choose which implementations of those functions are linked,
+/// e.g. vendor-optimized or table-based ones.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1, lambert_wm1_with_kernel, DefaultKernel};
+///
+/// assert_eq!(lambert_wm1_with_kernel::<DefaultKernel>(-0.2), lambert_wm1(-0.2));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_wm1_with_kernel, DefaultKernel};
+/// assert!(lambert_wm1_with_kernel::<DefaultKernel>(-1.0).is_nan());
+/// assert!(lambert_wm1_with_kernel::<DefaultKernel>(1.0).is_nan());
+/// ```
+pub fn lambert_wm1_with_kernel<K: MathKernel>(z: f64) -> f64 {
+    dwm1c::dwm1c::<K>(z, offset_from_branch_point(z))
+}
+
+/// Computes zc = z + 1/e without losing accuracy to cancellation when z is close to -1/e.
+///
+/// -1/e is represented as the sum of [`NEG_INV_E`] and [`NEG_INV_E_LO`](consts::NEG_INV_E_LO).
+/// When z is close to -1/e the subtraction of `NEG_INV_E` is exact, so the result is only rounded once.
+/// The argument `NEG_INV_E` itself is slightly smaller than -1/e, but is treated as the branch point.
+fn offset_from_branch_point(z: f64) -> f64 {
+    if z == NEG_INV_E {
+        0.0
+    } else {
+        (z - NEG_INV_E) - consts::NEG_INV_E_LO
+    }
 }
 
 /// The secondary branch of the Lambert W function, computed with `f32`s.
@@ -307,6 +833,9 @@ pub fn lambert_wm1(z: f64) -> f64 {
 /// which may result in slightly reduced accuracy.
 /// This potential accuracy reduction has not been quantified.
 ///
+/// The computation only uses `f32` arithmetic, so targets whose floating point unit only supports single precision
+/// do not need to emulate `f64` operations in software.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -328,23 +857,1417 @@ pub fn lambert_wm1(z: f64) -> f64 {
 /// assert!(lambert_wm1f(1.0).is_nan());
 /// ```
 ///
+/// -0.0 results in [`NEG_INFINITY`](f32::NEG_INFINITY), the limit of the function as the argument approaches 0 from below:
+///
+/// ```
+/// # use lambert_w::lambert_wm1f;
+/// assert_eq!(lambert_wm1f(-0.0), f32::NEG_INFINITY);
+/// ```
+///
+/// Subnormal arguments keep the same relative accuracy as normal ones, all the way down to the smallest subnormal `f32`:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_wm1f;
+///
+/// assert_relative_eq!(lambert_wm1f(-1e-40), -96.67476, max_relative = 4e-7);
+/// assert_relative_eq!(lambert_wm1f(-f32::from_bits(1)), -107.9607, max_relative = 4e-7);
+/// ```
+///
 /// # Reference
 ///
 /// [Toshio Fukushima, Precise and fast computation of Lambert W function by piecewise minimax rational function approximation with variable transformation](https://www.researchgate.net/publication/346309410_Precise_and_fast_computation_of_Lambert_W_function_by_piecewise_minimax_rational_function_approximation_with_variable_transformation).
+#[cfg_attr(lambert_w_strict_debug, track_caller)]
 pub fn lambert_wm1f(z: f32) -> f32 {
-    swm1f::swm1f(z)
+    let w = swm1f::swm1f(z);
+    #[cfg(lambert_w_strict_debug)]
+    strict::debug_assert_in_domain("lambert_wm1f", z, !w.is_nan() || z.is_nan());
+    w
 }
 
-/// Enables evaluation of the principal and secondary branches of the Lambert W function
-/// on the types that implement this trait.
-pub trait LambertW {
-    /// The type returned by the Lambert W functions when acting on a value of type `Self`.
-    type Output;
-
-    /// The principal branch of the Lambert W function.
-    fn lambert_w0(self) -> Self::Output;
-
-    /// The secondary branch of the Lambert W function.
+/// The principal branch of the Lambert W function, corrected such that the defining relation holds when evaluated in `f64`.
+///
+/// Computes the principal branch to 50 bits of accuracy, and then moves the result by single ulps until
+/// w·e^w, evaluated with `f64`s, is equal to the argument.
+/// If no float fulfills that, the float for which w·e^w is closest to the argument is returned.
+/// This is common for large arguments, where w·e^w changes by more than one ulp between neighbouring floats.
+///
+/// The exponential is computed with the same implementation that the rest of this crate uses,
+/// that is the standard library if the `std` feature is enabled and [`libm`] otherwise.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::lambert_w0_roundtrip;
+///
+/// let z = 2.0;
+/// let w = lambert_w0_roundtrip(z);
+///
+/// assert_eq!(w * w.exp(), z);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_roundtrip;
+/// assert!(lambert_w0_roundtrip(-1.0).is_nan());
+/// ```
+pub fn lambert_w0_roundtrip(z: f64) -> f64 {
+    roundtrip::w0_roundtrip(z)
+}
+
+/// The principal branch of the Lambert W function for non-negative arguments, computed without data-dependent branches.
+///
+/// Instead of selecting one of many subdomains like [`lambert_w0`], this function uses the same sequence of operations for all
+/// positive finite inputs: the global approximation of the function by Winitzki followed by two steps of
+/// the iteration by Fritsch, Shafer and Crowley. This gives predictable latency when the inputs are spread over many orders of magnitude,
+/// at the cost of a slightly lower accuracy. The largest relative error found in testing is about 3e-16.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_uniform, OMEGA};
+///
+/// assert_relative_eq!(lambert_w0_uniform(1.0), OMEGA);
+/// assert_relative_eq!(lambert_w0_uniform(f64::MAX), 703.2270331047702, max_relative = 4e-16);
+/// ```
+///
+/// Negative arguments result in [`NAN`](f64::NAN), even though the principal branch is defined down to -1/e:
+///
+/// ```
+/// # use lambert_w::lambert_w0_uniform;
+/// assert!(lambert_w0_uniform(-0.1).is_nan());
+/// ```
+pub fn lambert_w0_uniform(z: f64) -> f64 {
+    uniform::w0_uniform(z)
+}
+
+/// A low-accuracy approximation of the principal branch of the Lambert W function with about 4 correct significant digits.
+///
+/// Evaluates a single rational function of degree 4 in ln(1 + z), with no branches other than the check of the domain,
+/// so the result is smooth and the latency does not depend on the argument. The expression is simple enough
+/// to be ported to e.g. a shader. This is meant for applications like games and graphics that need speed
+/// and smoothness more than accuracy. The largest relative error found in testing is 1.5e-4.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_approx, OMEGA};
+///
+/// assert_relative_eq!(lambert_w0_approx(1.0), OMEGA, max_relative = 1.5e-4);
+/// assert_relative_eq!(lambert_w0_approx(1e100), 224.843_106_111_246_5, max_relative = 1.5e-4);
+/// ```
+///
+/// Negative arguments result in [`NAN`](f64::NAN), even though the principal branch is defined down to -1/e:
+///
+/// ```
+/// # use lambert_w::lambert_w0_approx;
+/// assert!(lambert_w0_approx(-0.1).is_nan());
+/// ```
+pub fn lambert_w0_approx(z: f64) -> f64 {
+    quick::w0_quick(z)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// for arguments that are known to be at most about 43.2.
+///
+/// Only uses the approximations on the first two subdomains of [`lambert_w0`],
+/// so it needs fewer comparisons to select one of them, and the others are not linked into the binary
+/// if nothing else uses them. Gives the same results as [`lambert_w0`] for arguments up to z + 1/e = 43.61,
+/// i.e. z ≈ 43.246, where the function value is 2.754 (the second of [`W0_BREAKPOINTS`](consts::W0_BREAKPOINTS)).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_small, OMEGA};
+///
+/// assert_eq!(lambert_w0_small(1.0), OMEGA);
+/// assert_eq!(lambert_w0_small(40.0), lambert_w0(40.0));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than about 43.246 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_small;
+/// assert!(lambert_w0_small(-1.0).is_nan());
+/// assert!(lambert_w0_small(50.0).is_nan());
+/// ```
+pub fn lambert_w0_small(z: f64) -> f64 {
+    w0_with(z, dw0c::dw0c_small::<DefaultKernel>)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// for arguments that are known to be larger than about 3.97e19.
+///
+/// Only uses the approximations on the last two subdomains of [`lambert_w0`], which are functions of the logarithm
+/// of the argument, so it needs fewer comparisons to select one of them, and the others are not linked into the binary
+/// if nothing else uses them. Gives the same results as [`lambert_w0`] for arguments larger than 3.968e19,
+/// where the function value is 41.404 (the last of [`W0_BREAKPOINTS`](consts::W0_BREAKPOINTS)).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_large};
+///
+/// assert_eq!(lambert_w0_large(1e30), lambert_w0(1e30));
+/// assert_eq!(lambert_w0_large(f64::INFINITY), f64::INFINITY);
+/// ```
+///
+/// Arguments smaller than about 3.968e19 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_large;
+/// assert!(lambert_w0_large(1e19).is_nan());
+/// assert!(lambert_w0_large(f64::NAN).is_nan());
+/// ```
+pub fn lambert_w0_large(z: f64) -> f64 {
+    dw0c::dw0c_large::<DefaultKernel>(offset_from_branch_point(z))
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// without steps between the approximations on neighboring subdomains.
+///
+/// The rational functions that [`lambert_w0`] uses on neighboring subdomains differ by a few ulps at the seam between them,
+/// which shows up as a step when the function is differenced across the seam. This function blends the approximation
+/// after each seam into the one before it over the first 1/1024 of its subdomain, and clamps every approximation
+/// to its values at the seams. This roughly halves the steps, and keeps the function monotone across the seams.
+/// Away from the seams it gives the same results as [`lambert_w0`], but it is slower everywhere,
+/// so only use it when the function is differenced, e.g. in finite difference derivatives.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_smooth, OMEGA};
+///
+/// assert_eq!(lambert_w0_smooth(1.0), OMEGA);
+/// assert_eq!(lambert_w0_smooth(10.0), lambert_w0(10.0));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_smooth;
+/// assert!(lambert_w0_smooth(-1.0).is_nan());
+/// ```
+pub fn lambert_w0_smooth(z: f64) -> f64 {
+    w0_with(z, seam::dw0c_smooth::<DefaultKernel>)
+}
+
+/// The secondary branch of the Lambert W function computed to 50 bits of accuracy,
+/// without steps between the approximations on neighboring subdomains.
+///
+/// Blends the approximations that [`lambert_wm1`] uses on neighboring subdomains into each other
+/// in the same way as [`lambert_w0_smooth`]. Away from the seams it gives the same results as [`lambert_wm1`],
+/// but it is slower everywhere.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1, lambert_wm1_smooth};
+///
+/// assert_eq!(lambert_wm1_smooth(-0.3), lambert_wm1(-0.3));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1_smooth;
+/// assert!(lambert_wm1_smooth(-1.0).is_nan());
+/// assert!(lambert_wm1_smooth(1.0).is_nan());
+/// ```
+pub fn lambert_wm1_smooth(z: f64) -> f64 {
+    seam::dwm1c_smooth::<DefaultKernel>(z, offset_from_branch_point(z))
+}
+
+/// Returns an iterator over `n` equally spaced arguments from `start` to `end`, inclusive,
+/// paired with the principal branch of the Lambert W function computed to 50 bits of accuracy at them.
+///
+/// Gives the same values as calling [`lambert_w0`] on each argument, and the last argument is exactly `end`.
+/// This is useful when plotting or tabulating the function.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_linspace, OMEGA};
+///
+/// let points: Vec<(f64, f64)> = lambert_w0_linspace(0.0, 2.0, 3).collect();
+///
+/// assert_eq!(points, [(0.0, 0.0), (1.0, OMEGA), (2.0, lambert_w0(2.0))]);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_linspace;
+/// let (x, w) = lambert_w0_linspace(-1.0, 0.0, 2).next().unwrap();
+///
+/// assert_eq!(x, -1.0);
+/// assert!(w.is_nan());
+/// ```
+pub fn lambert_w0_linspace(start: f64, end: f64, n: usize) -> W0Linspace {
+    W0Linspace::new(start, end, n)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// with inputs slightly below -1/e treated as -1/e.
+///
+/// Arguments that are at most `ulp_tolerance` [ulps](https://en.wikipedia.org/wiki/Unit_in_the_last_place)
+/// smaller than [`NEG_INV_E`] are treated as the branch point, where the function value is -1.
+/// This is useful when the argument is mathematically at least -1/e, but has been rounded to a value just below it.
+/// Other arguments give the same result as [`lambert_w0`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_clamped, NEG_INV_E};
+///
+/// // The next float below -1/e.
+/// let z = f64::from_bits(NEG_INV_E.to_bits() + 1);
+///
+/// assert!(lambert_w0(z).is_nan());
+/// assert_eq!(lambert_w0_clamped(z, 4), -1.0);
+/// ```
+///
+/// Arguments further below -1/e than the tolerance still result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::{lambert_w0_clamped, NEG_INV_E};
+/// let z = f64::from_bits(NEG_INV_E.to_bits() + 5);
+///
+/// assert!(lambert_w0_clamped(z, 4).is_nan());
+/// ```
+pub fn lambert_w0_clamped(z: f64, ulp_tolerance: u64) -> f64 {
+    // Both numbers are negative, so the larger bit pattern belongs to the number with the larger magnitude.
+    if z < NEG_INV_E && z.to_bits() - NEG_INV_E.to_bits() <= ulp_tolerance {
+        -1.0
+    } else {
+        lambert_w0(z)
+    }
+}
+
+/// Computes W0(z) + 1 for the principal branch of the Lambert W function, where `zc` = z + 1/e is the offset of the argument from the branch point.
+///
+/// Close to the branch point the function value is close to -1, so computing W0(z) and then adding 1
+/// loses the relative accuracy of the result. This function instead uses the Puiseux series of the function
+/// around the branch point in terms of p = sqrt(2e·zc) when p ≤ 0.15, which gives W0(z) + 1 to close to full `f64` relative accuracy.
+/// For larger offsets it uses the approximation with 50 bits of accuracy.
+///
+/// Taking the offset as the argument lets callers that know it in higher precision than z avoid the cancellation in z + 1/e.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_w0_near_branch;
+///
+/// // W0(z) + 1 where z is 1e-20 above -1/e.
+/// let w_plus_one = lambert_w0_near_branch(1e-20);
+///
+/// assert_relative_eq!(w_plus_one, 2.331_643_981_415_905_4e-10, max_relative = 1e-15);
+/// ```
+///
+/// Negative offsets result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_near_branch;
+/// assert!(lambert_w0_near_branch(-1e-20).is_nan());
+/// ```
+pub fn lambert_w0_near_branch(zc: f64) -> f64 {
+    if zc < 0.0 || zc.is_nan() {
+        return f64::NAN;
+    }
+
+    let p = puiseux::p_from_zc(zc);
+    if p <= puiseux::MAX_P {
+        puiseux::w_plus_one(p)
+    } else {
+        dw0c::dw0c::<DefaultKernel>(zc) + 1.0
+    }
+}
+
+/// Computes W-1(z) + 1 for the secondary branch of the Lambert W function, where `zc` = z + 1/e is the offset of the argument from the branch point.
+///
+/// Close to the branch point the function value is close to -1, so computing W-1(z) and then adding 1
+/// loses the relative accuracy of the result. This function instead uses the Puiseux series of the function
+/// around the branch point in terms of p = -sqrt(2e·zc) when |p| ≤ 0.15, which gives W-1(z) + 1 to close to full `f64` relative accuracy.
+/// For larger offsets it uses the approximation with 50 bits of accuracy.
+///
+/// Taking the offset as the argument lets callers that know it in higher precision than z avoid the cancellation in z + 1/e.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_wm1_near_branch;
+///
+/// // W-1(z) + 1 where z is 1e-20 above -1/e.
+/// let w_plus_one = lambert_wm1_near_branch(1e-20);
+///
+/// assert_relative_eq!(w_plus_one, -2.331_643_981_778_343e-10, max_relative = 1e-15);
+/// ```
+///
+/// Negative offsets, and offsets of 1/e or larger, result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1_near_branch;
+/// assert!(lambert_wm1_near_branch(-1e-20).is_nan());
+/// assert!(lambert_wm1_near_branch(1.0).is_nan());
+/// ```
+pub fn lambert_wm1_near_branch(zc: f64) -> f64 {
+    if zc < 0.0 || zc.is_nan() {
+        return f64::NAN;
+    }
+
+    let p = puiseux::p_from_zc(zc);
+    if p <= puiseux::MAX_P {
+        puiseux::w_plus_one(-p)
+    } else {
+        dwm1c::dwm1c::<DefaultKernel>(zc + NEG_INV_E, zc) + 1.0
+    }
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// where `zc` = z + 1/e is the offset of the argument z from the branch point.
+///
+/// This evaluates the same approximations as [`lambert_w0`], but takes the offset that they are functions of directly,
+/// for callers that know it in higher precision than z itself, e.g. because they computed it analytically.
+/// [`lambert_w0`] instead computes the offset from z, which rounds it.
+///
+/// The contract is:
+///
+/// - 1/e here is the exact real number, not the rounded [`NEG_INV_E`].
+/// - `zc` of 0 gives exactly -1, and infinity gives infinity.
+/// - Negative offsets and [`NAN`](f64::NAN) result in [`NAN`](f64::NAN).
+/// - Close to z = 0, i.e. for `zc` close to 1/e, the function value is close to 0 while the offset does not determine z
+///   to better than about 1e-17, so the result only has an absolute accuracy of about that size there.
+///   Use [`lambert_w0`] for arguments close to 0.
+///
+/// See [`lambert_w0_near_branch`] for W0(z) + 1 with full relative accuracy close to the branch point.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0, lambert_w0_from_offset};
+///
+/// // z = 1 - 1/e exactly.
+/// assert_relative_eq!(lambert_w0_from_offset(1.0), lambert_w0(1.0 - 1.0 / core::f64::consts::E), max_relative = 1e-15);
+/// assert_eq!(lambert_w0_from_offset(0.0), -1.0);
+/// ```
+///
+/// Negative offsets result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_from_offset;
+/// assert!(lambert_w0_from_offset(-1e-20).is_nan());
+/// ```
+pub fn lambert_w0_from_offset(zc: f64) -> f64 {
+    if zc == 0.0 {
+        -1.0
+    } else {
+        dw0c::dw0c::<DefaultKernel>(zc)
+    }
+}
+
+/// The secondary branch of the Lambert W function computed to 50 bits of accuracy,
+/// where `zc` = z + 1/e is the offset of the argument z from the branch point.
+///
+/// This evaluates the same approximations as [`lambert_wm1`], but takes the offset from the branch point directly,
+/// for callers that know it in higher precision than z itself. The approximations close to the branch point
+/// are functions of the offset, and those closer to 0 are functions of z, which is then computed as `zc` - 1/e.
+///
+/// The contract is:
+///
+/// - 1/e here is the exact real number, not the rounded [`NEG_INV_E`].
+/// - `zc` of 0 gives exactly -1.
+/// - Negative offsets, offsets of 1/e or larger, and [`NAN`](f64::NAN) result in [`NAN`](f64::NAN).
+/// - Close to z = 0, i.e. for `zc` close to 1/e, the offset does not determine z to better than about 1e-17,
+///   which limits the accuracy of the result. Use [`lambert_wm1`] for arguments close to 0.
+///
+/// See [`lambert_wm1_near_branch`] for W-1(z) + 1 with full relative accuracy close to the branch point.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_wm1, lambert_wm1_from_offset};
+///
+/// // z = 0.1 - 1/e exactly.
+/// assert_relative_eq!(lambert_wm1_from_offset(0.1), lambert_wm1(0.1 - 1.0 / core::f64::consts::E), max_relative = 1e-15);
+/// assert_eq!(lambert_wm1_from_offset(0.0), -1.0);
+/// ```
+///
+/// Negative offsets, and offsets of 1/e or larger, result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1_from_offset;
+/// assert!(lambert_wm1_from_offset(-1e-20).is_nan());
+/// assert!(lambert_wm1_from_offset(1.0).is_nan());
+/// ```
+pub fn lambert_wm1_from_offset(zc: f64) -> f64 {
+    if zc == 0.0 {
+        -1.0
+    } else {
+        dwm1c::dwm1c::<DefaultKernel>(zc + NEG_INV_E, zc)
+    }
+}
+
+/// Returns the subdomain whose rational approximation [`lambert_w0`] evaluates at `z`,
+/// for diagnosing the accuracy or performance of the function at an argument,
+/// or profiling which approximations a workload uses.
+///
+/// Returns `None` if [`lambert_w0`] evaluates none of the approximations at `z`. That is the case for arguments
+/// where the value is known exactly, arguments so close to 0 that the Taylor series of the function is used,
+/// and arguments that result in [`NAN`](f64::NAN) or [`INFINITY`](f64::INFINITY).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_segment, SegmentId};
+///
+/// assert_eq!(lambert_w0_segment(0.5), Some(SegmentId::X1));
+/// assert_eq!(lambert_w0_segment(1e20), Some(SegmentId::U18));
+/// assert_eq!(lambert_w0_segment(1.0), None);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in `None`:
+///
+/// ```
+/// # use lambert_w::lambert_w0_segment;
+/// assert_eq!(lambert_w0_segment(-1.0), None);
+/// ```
+pub fn lambert_w0_segment(z: f64) -> Option<SegmentId> {
+    segment_id::w0_segment(z)
+}
+
+/// Returns the subdomain whose rational approximation [`lambert_wm1`] evaluates at `z`,
+/// for diagnosing the accuracy or performance of the function at an argument,
+/// or profiling which approximations a workload uses.
+///
+/// Returns `None` if [`lambert_wm1`] evaluates none of the approximations at `z`,
+/// which is the case for arguments that result in [`NAN`](f64::NAN) or [`NEG_INFINITY`](f64::NEG_INFINITY).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1_segment, SegmentId};
+///
+/// assert_eq!(lambert_wm1_segment(-0.36), Some(SegmentId::Xm1));
+/// assert_eq!(lambert_wm1_segment(-1e-300), Some(SegmentId::Vm10));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than or equal to 0 result in `None`:
+///
+/// ```
+/// # use lambert_w::lambert_wm1_segment;
+/// assert_eq!(lambert_wm1_segment(-1.0), None);
+/// assert_eq!(lambert_wm1_segment(1.0), None);
+/// ```
+pub fn lambert_wm1_segment(z: f64) -> Option<SegmentId> {
+    segment_id::wm1_segment(z)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// together with an empirical estimate of the absolute error of the result.
+///
+/// Returns the same value as [`lambert_w0`] as the first element of the tuple. The second element estimates
+/// the absolute difference between that value and the exact function value at the argument.
+/// The estimate is the sum of the largest relative error of the rational approximations that the crate uses,
+/// as found by testing against arbitrary precision evaluations and doubled as a margin, the largest absolute rounding error
+/// of their evaluation found in the same way, and the error in the offset of the argument from the branch point,
+/// propagated through the derivative of the function.
+/// The latter term is negligible except at [`NEG_INV_E`], which is treated as the branch point
+/// even though it differs from -1/e by about 1.2e-17.
+///
+/// The estimate is not a guaranteed bound, since it is not derived from the minimax errors of the approximations
+/// or from a rounding error analysis of their evaluation. Do not rely on it where a rigorous enclosure is needed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_with_error_estimate, NEG_INV_E, OMEGA};
+///
+/// let (w, error) = lambert_w0_with_error_estimate(1.0);
+///
+/// assert_eq!(w, OMEGA);
+/// assert!(error < 2e-15);
+///
+/// // At NEG_INV_E the estimate is much larger.
+/// let (w, error) = lambert_w0_with_error_estimate(NEG_INV_E);
+/// assert_eq!(w, -1.0);
+/// assert!(error > 1e-9);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN) for both the value and the estimate:
+///
+/// ```
+/// # use lambert_w::lambert_w0_with_error_estimate;
+/// let (w, error) = lambert_w0_with_error_estimate(-1.0);
+///
+/// assert!(w.is_nan());
+/// assert!(error.is_nan());
+/// ```
+pub fn lambert_w0_with_error_estimate(z: f64) -> (f64, f64) {
+    error_estimate::w0_with_error_estimate(z)
+}
+
+/// The principal branch of the Lambert W function computed to 50 bits of accuracy,
+/// together with a certified enclosure of the exact function value.
+///
+/// Returns the same value as [`lambert_w0`] together with an interval around it. The interval is certified to contain the exact
+/// function value by evaluating the residual w·e^w - z at its ends together with bounds on the rounding errors of that evaluation:
+/// since w·e^w is increasing on the principal branch, the function value lies between two points where the residual has different signs.
+/// The interval starts out a few ulps wide and is widened until the check succeeds, which only takes more than one attempt close to the branch point.
+/// If the check never succeeds the result is not certified, and the enclosure is the entire range of the branch.
+///
+/// The certification assumes that the exponential function of the math library has a relative error of at most 2 ulps.
+/// This is true of the [`libm`] crate, which is used for all exponentials with the `deterministic` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_verified};
+///
+/// let w = lambert_w0_verified(2.0);
+///
+/// assert!(w.is_certified());
+/// assert_eq!(w.value(), lambert_w0(2.0));
+///
+/// let (lo, hi) = w.enclosure();
+/// assert!(lo < w.value() && w.value() < hi);
+/// assert!(hi - lo < 1e-14);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN) and are not certified:
+///
+/// ```
+/// # use lambert_w::lambert_w0_verified;
+/// let w = lambert_w0_verified(-1.0);
+///
+/// assert!(w.value().is_nan());
+/// assert!(!w.is_certified());
+/// ```
+pub fn lambert_w0_verified(z: f64) -> Verified {
+    verified::w0_verified(z)
+}
+
+/// Propagates the uncertainty of an argument of the principal branch of the Lambert W function to first order.
+///
+/// Takes the mean `value` and standard deviation `sigma` of the argument, and returns the value of the function at the mean,
+/// computed to 50 bits of accuracy, together with the standard deviation of the result given by linear error propagation:
+/// `sigma` multiplied by the derivative W0'(z) = 1/(e^W0(z)·(1 + W0(z))) at the mean.
+///
+/// The derivative grows without bound close to the branch point at -1/e, and linear propagation underestimates the uncertainty
+/// when `sigma` is not small compared to the distance to it. Use [`lambert_w0_uncertain_second_order`] there.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_uncertain, OMEGA};
+///
+/// let (w, sigma) = lambert_w0_uncertain(1.0, 0.01);
+///
+/// assert_eq!(w, OMEGA);
+/// // W0'(1) = Ω/(1 + Ω)
+/// assert_relative_eq!(sigma, 0.01 * OMEGA / (1.0 + OMEGA));
+/// ```
+///
+/// Means smaller than -1/e (≈ -0.36787944117144233), and negative standard deviations, result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_uncertain;
+/// let (w, sigma) = lambert_w0_uncertain(-1.0, 0.01);
+/// assert!(w.is_nan() && sigma.is_nan());
+///
+/// let (w, sigma) = lambert_w0_uncertain(1.0, -0.01);
+/// assert!(w.is_nan() && sigma.is_nan());
+/// ```
+pub fn lambert_w0_uncertain(value: f64, sigma: f64) -> (f64, f64) {
+    uncertain::w0_uncertain(value, sigma)
+}
+
+/// Propagates the uncertainty of a normally distributed argument of the principal branch of the Lambert W function to second order.
+///
+/// Takes the mean `value` and standard deviation `sigma` of the argument, and returns the mean and standard deviation of the function
+/// of the argument, computed from the Taylor expansion of the function to second order around the mean.
+/// Compared to [`lambert_w0_uncertain`] this shifts the mean by W0''(z)·σ²/2 and adds (W0''(z)·σ²)²/2 to the variance,
+/// which accounts for the curvature of the function. This matters close to the branch point at -1/e, where the derivatives
+/// grow without bound. At the branch point itself any non-zero `sigma` gives an infinite standard deviation.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0, lambert_w0_uncertain, lambert_w0_uncertain_second_order};
+///
+/// let (w1, sigma1) = lambert_w0_uncertain(-0.35, 0.001);
+/// let (w2, sigma2) = lambert_w0_uncertain_second_order(-0.35, 0.001);
+///
+/// // The function is concave, so the mean of the result is smaller than the function value at the mean.
+/// assert!(w2 < w1);
+/// assert!(sigma2 > sigma1);
+/// ```
+///
+/// Means smaller than -1/e (≈ -0.36787944117144233), and negative standard deviations, result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_uncertain_second_order;
+/// let (w, sigma) = lambert_w0_uncertain_second_order(-1.0, 0.01);
+/// assert!(w.is_nan() && sigma.is_nan());
+/// ```
+pub fn lambert_w0_uncertain_second_order(value: f64, sigma: f64) -> (f64, f64) {
+    uncertain::w0_uncertain_second_order(value, sigma)
+}
+
+/// The secondary branch of the Lambert W function computed to 50 bits of accuracy,
+/// together with a certified enclosure of the exact function value.
+///
+/// Returns the same value as [`lambert_wm1`] together with an interval around it that is certified to contain the exact function value.
+/// See [`lambert_w0_verified`] for how the interval is found and certified. On the secondary branch w·e^w is decreasing.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1, lambert_wm1_verified};
+///
+/// let z = -f64::ln(2.0) / 2.0;
+/// let w = lambert_wm1_verified(z);
+///
+/// assert!(w.is_certified());
+/// assert_eq!(w.value(), lambert_wm1(z));
+///
+/// let (lo, hi) = w.enclosure();
+/// assert!(lo <= -f64::ln(4.0) && -f64::ln(4.0) <= hi);
+/// ```
+///
+/// Arguments outside the domain of the secondary branch result in [`NAN`](f64::NAN) and are not certified:
+///
+/// ```
+/// # use lambert_w::lambert_wm1_verified;
+/// let w = lambert_wm1_verified(1.0);
+///
+/// assert!(w.value().is_nan());
+/// assert!(!w.is_certified());
+/// ```
+pub fn lambert_wm1_verified(z: f64) -> Verified {
+    verified::wm1_verified(z)
+}
+
+/// Computes W0(-e^(-y)) for the principal branch of the Lambert W function directly from `y` ≥ 1.
+///
+/// For large `y` the argument -e^(-y) is tiny and eventually underflows, and the function value is just as tiny.
+/// This function never forms the argument, but works with `y` throughout, so the result keeps its full relative accuracy
+/// as long as it is a normal float. For `y` close to 1 the offset of the argument from the branch point is also computed
+/// from `y` without cancellation.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_w0_neg_exp;
+///
+/// assert_eq!(lambert_w0_neg_exp(1.0), -1.0);
+/// assert_relative_eq!(lambert_w0_neg_exp(2.0), -0.15859433956303937, max_relative = 1e-15);
+///
+/// // -e^(-700) ≈ -9.86e-305 is close to the smallest normal float.
+/// assert_relative_eq!(lambert_w0_neg_exp(700.0), -9.85967654375977e-305, max_relative = 1e-15);
+/// ```
+///
+/// Arguments smaller than 1 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_neg_exp;
+/// assert!(lambert_w0_neg_exp(0.5).is_nan());
+/// ```
+pub fn lambert_w0_neg_exp(y: f64) -> f64 {
+    neg_exp::w0_neg_exp(y)
+}
+
+/// Improves an approximation `w` of a value of the Lambert W function at `z` with one step of Halley's method.
+///
+/// Halley's method has cubic convergence, so a single step roughly triples the number of correct digits
+/// of an approximation that is already close to the true value. Repeated application converges to the
+/// branch of the function that `w` is closest to.
+///
+/// This lets you combine your own cheap approximations, or the approximations with 24 bits of accuracy
+/// in this crate, with a refinement step to get the accuracy/speed trade-off you need.
+/// The refinement computes an exponential, so it overflows for `w` larger than about 709.
+/// Use [`fritsch_refine`] for such values.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::{halley_refine, sp_lambert_w0, OMEGA};
+///
+/// let w = sp_lambert_w0(1.0);
+/// assert_abs_diff_eq!(w, OMEGA, epsilon = 1e-7);
+///
+/// let refined = halley_refine(1.0, w);
+/// assert_abs_diff_eq!(refined, OMEGA);
+/// ```
+///
+/// The step is undefined at the branch point w = -1, and [`NAN`](f64::NAN) inputs result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::halley_refine;
+/// assert!(halley_refine(f64::NAN, 1.0).is_nan());
+/// assert!(halley_refine(1.0, f64::NAN).is_nan());
+/// ```
+pub fn halley_refine(z: f64, w: f64) -> f64 {
+    refine::halley(z, w)
+}
+
+/// Improves an approximation `w` of a value of the Lambert W function at `z` with one step of
+/// the iteration by Fritsch, Shafer and Crowley.
+///
+/// The iteration has quartic convergence, so a single step roughly quadruples the number of correct digits
+/// of an approximation that is already close to the true value.
+/// It uses the logarithm of z/w instead of the exponential of w, so unlike [`halley_refine`] it can refine
+/// arbitrarily large function values. However, it needs z and w to have the same sign.
+/// If `w` already solves the equation exactly it is returned unchanged, which includes -1 at the branch point.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{fritsch_refine, sp_lambert_wm1};
+///
+/// let z = -f64::ln(2.0) / 2.0;
+/// let w = sp_lambert_wm1(z);
+///
+/// let refined = fritsch_refine(z, w);
+/// assert_relative_eq!(refined, -f64::ln(4.0));
+/// ```
+///
+/// Inputs where z and w have different signs result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::fritsch_refine;
+/// assert!(fritsch_refine(1.0, -0.5).is_nan());
+/// ```
+pub fn fritsch_refine(z: f64, w: f64) -> f64 {
+    refine::fritsch_unless_solved(z, w)
+}
+
+/// The infinite power tower z^z^z^... computed with the principal branch of the Lambert W function.
+///
+/// This is evaluated as -W0(-ln(z))/ln(z), where W0 is computed to 50 bits of accuracy.
+/// The removable singularity at z = 1 is handled with a series expansion,
+/// and arguments that end up just outside the domain of W0 due to rounding
+/// near the upper end of the interval of convergence are treated as the branch point.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::tetration_limit;
+///
+/// let two = tetration_limit(f64::sqrt(2.0));
+///
+/// assert_abs_diff_eq!(two, 2.0, epsilon = 1e-15);
+/// ```
+///
+/// The tower only converges for arguments in the interval \[e^(-e), e^(1/e)\] (≈ \[0.06598803584531254, 1.444667861009766\]).
+/// Arguments outside that interval result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::tetration_limit;
+/// assert!(tetration_limit(0.05).is_nan());
+/// assert!(tetration_limit(1.5).is_nan());
+/// ```
+pub fn tetration_limit(z: f64) -> f64 {
+    // This also catches NAN.
+    if !(consts::E_POW_NEG_E..=consts::E_POW_INV_E).contains(&z) {
+        return f64::NAN;
+    }
+
+    let x = -elementary::ln(z);
+
+    if x.abs() < 1e-3 {
+        // The Taylor series of W0(x)/x around x = 0.
+        // The first omitted term is smaller than 2.4e-17 on this interval.
+        1.0 + x * (-1.0 + x * (1.5 + x * (-8.0 / 3.0 + x * (125.0 / 24.0 + x * -10.8))))
+    } else {
+        // At the upper end of the interval the logarithm can round to a value that is
+        // slightly outside the domain of W0.
+        lambert_w0(x.max(NEG_INV_E)) / x
+    }
+}
+
+/// The principal branch of the Lambert W function evaluated on any type that implements [`Float`](num_traits::Float).
+///
+/// Computes an initial approximation with [`lambert_w0`] and then improves it with one step of Halley's method
+/// carried out in the arithmetic of the generic type. This gives close to the full precision of
+/// any type with less than about 150 bits of precision, and lets wrapper types, e.g. instrumented floats,
+/// observe the arithmetic that depends on the input.
+///
+/// Only available with the `num-traits` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_generic, OMEGA};
+///
+/// assert_relative_eq!(lambert_w0_generic(1.0_f64), OMEGA);
+/// assert_relative_eq!(lambert_w0_generic(1.0_f32), 0.567_143_3);
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233), and arguments that can not be represented as an `f64`,
+/// result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_w0_generic;
+/// assert!(lambert_w0_generic(-1.0_f64).is_nan());
+/// ```
+#[cfg(feature = "num-traits")]
+pub fn lambert_w0_generic<T: num_traits::Float>(z: T) -> T {
+    generic::w0_generic(z)
+}
+
+/// The secondary branch of the Lambert W function evaluated on any type that implements [`Float`](num_traits::Float).
+///
+/// Computes an initial approximation with [`lambert_wm1`] and then improves it with one step of Halley's method
+/// carried out in the arithmetic of the generic type. This gives close to the full precision of
+/// any type with less than about 150 bits of precision, and lets wrapper types, e.g. instrumented floats,
+/// observe the arithmetic that depends on the input.
+///
+/// Only available with the `num-traits` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_wm1_generic;
+///
+/// let mln4 = lambert_wm1_generic(-f64::ln(2.0) / 2.0);
+///
+/// assert_relative_eq!(mln4, -f64::ln(4.0));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_wm1_generic;
+/// assert!(lambert_wm1_generic(-1.0_f32).is_nan());
+/// assert!(lambert_wm1_generic(1.0_f32).is_nan());
+/// ```
+#[cfg(feature = "num-traits")]
+pub fn lambert_wm1_generic<T: num_traits::Float>(z: T) -> T {
+    generic::wm1_generic(z)
+}
+
+/// The principal branch of the Lambert W function evaluated on the dual numbers of the [`num-dual`](num_dual) crate.
+///
+/// The real part of the result is the same as the result of [`lambert_w0`] on the real part of the argument,
+/// and the derivative parts are propagated exactly through the function, to any order that the dual number type supports.
+/// This lets derivatives of expressions that contain the function be computed with automatic differentiation.
+/// First and second derivatives, e.g. of [`Dual2_64`](num_dual::Dual2_64) and [`HyperDual64`](num_dual::HyperDual64),
+/// are computed from the closed forms W'(z) = W(z)/(z·(1 + W(z))) and W''(z) = -W'(z)²·(2 + W(z))/(1 + W(z)),
+/// so Hessians of expressions that contain the function are exact.
+///
+/// If the real part of the argument is outside the domain of the branch, or at the branch point
+/// where the derivatives are infinite, every part of the result is NaN.
+///
+/// Only available with the `num-dual` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_dual, OMEGA};
+/// use num_dual::Dual64;
+///
+/// let w = lambert_w0_dual(Dual64::from_re(1.0).derivative());
+///
+/// assert_eq!(w.re, OMEGA);
+/// // W0'(1) = Ω/(1 + Ω)
+/// assert_relative_eq!(w.eps, OMEGA / (1.0 + OMEGA));
+/// ```
+///
+/// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_w0_dual;
+/// # use num_dual::Dual64;
+/// let w = lambert_w0_dual(Dual64::from_re(-1.0).derivative());
+///
+/// assert!(w.re.is_nan() && w.eps.is_nan());
+/// ```
+///
+/// Second derivatives with hyper-dual numbers, here of W0(x·y) with respect to x and y at (1, 1):
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0_dual, OMEGA};
+/// use num_dual::HyperDual64;
+///
+/// let x = HyperDual64::new(1.0, 1.0, 0.0, 0.0);
+/// let y = HyperDual64::new(1.0, 0.0, 1.0, 0.0);
+/// let w = lambert_w0_dual(x * y);
+///
+/// // The mixed derivative is W0'(1) + W0''(1).
+/// let first = OMEGA / (1.0 + OMEGA);
+/// let second = -first * first * (2.0 + OMEGA) / (1.0 + OMEGA);
+/// assert_relative_eq!(w.eps1eps2, first + second);
+/// ```
+#[cfg(feature = "num-dual")]
+pub fn lambert_w0_dual<D: num_dual::DualNum<Primitive = f64>>(z: D) -> D {
+    dual::w0_dual(z)
+}
+
+/// The secondary branch of the Lambert W function evaluated on the dual numbers of the [`num-dual`](num_dual) crate.
+///
+/// The real part of the result is the same as the result of [`lambert_wm1`] on the real part of the argument,
+/// and the derivative parts are propagated exactly through the function, to any order that the dual number type supports.
+///
+/// If the real part of the argument is outside the domain of the branch, or at the branch point
+/// where the derivatives are infinite, every part of the result is NaN.
+///
+/// Only available with the `num-dual` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::lambert_wm1_dual;
+/// use num_dual::Dual64;
+///
+/// let z = -f64::ln(2.0) / 2.0;
+/// let w = lambert_wm1_dual(Dual64::from_re(z).derivative());
+///
+/// let mln4 = -f64::ln(4.0);
+/// assert_relative_eq!(w.re, mln4);
+/// // W'(z) = W(z)/(z·(1 + W(z)))
+/// assert_relative_eq!(w.eps, mln4 / (z * (1.0 + mln4)));
+/// ```
+///
+/// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_wm1_dual;
+/// # use num_dual::Dual64;
+/// let w = lambert_wm1_dual(Dual64::from_re(1.0).derivative());
+///
+/// assert!(w.re.is_nan() && w.eps.is_nan());
+/// ```
+#[cfg(feature = "num-dual")]
+pub fn lambert_wm1_dual<D: num_dual::DualNum<Primitive = f64>>(z: D) -> D {
+    dual::wm1_dual(z)
+}
+
+/// The principal branch of the Lambert W function evaluated on the SIMD types of the [`simba`] crate.
+///
+/// Works on any type that implements [`SimdRealField`](simba::simd::SimdRealField) with `f64` lanes,
+/// e.g. the SIMD types used by `nalgebra`. The lanes can lie in different parts of the domain, so instead of selecting one of many subdomains
+/// like [`lambert_w0`], this function uses the same sequence of operations on every lane: an initial approximation
+/// that is refined with two steps of the iteration by Fritsch, Shafer and Crowley, combined with the Puiseux series
+/// around the branch point by blending the results of the lanes. The largest relative error found in testing is about 1.5e-15.
+///
+/// Only available with the `simba` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0, lambert_w0_simd};
+/// use simba::simd::{AutoF64x4, SimdValue};
+///
+/// let z = AutoF64x4::from([-0.3, 0.0, 1.0, 1e100]);
+/// let w = lambert_w0_simd(z);
+///
+/// for lane in 0..4 {
+///     assert_relative_eq!(w.extract(lane), lambert_w0(z.extract(lane)), max_relative = 1e-15);
+/// }
+/// ```
+///
+/// Lanes with arguments smaller than -1/e (≈ -0.36787944117144233) result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0_simd;
+/// # use simba::simd::{AutoF64x4, SimdValue};
+/// let w = lambert_w0_simd(AutoF64x4::from([-1.0, 1.0, f64::NAN, 2.0]));
+///
+/// assert!(w.extract(0).is_nan());
+/// assert!(!w.extract(1).is_nan());
+/// assert!(w.extract(2).is_nan());
+/// ```
+#[cfg(feature = "simba")]
+pub fn lambert_w0_simd<T: simba::simd::SimdRealField<Element = f64>>(z: T) -> T {
+    simd_real::w0_simd(z)
+}
+
+/// The secondary branch of the Lambert W function evaluated on the SIMD types of the [`simba`] crate.
+///
+/// Works on any type that implements [`SimdRealField`](simba::simd::SimdRealField) with `f64` lanes,
+/// in the same way as [`lambert_w0_simd`]. The initial approximation away from the branch point is the first terms of
+/// the asymptotic expansion of the branch at 0. The largest relative error found in testing is about 1.5e-15.
+///
+/// Only available with the `simba` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_wm1, lambert_wm1_simd};
+/// use simba::simd::{AutoF64x4, SimdValue};
+///
+/// let z = AutoF64x4::from([-0.367, -0.3, -1e-5, -1e-300]);
+/// let w = lambert_wm1_simd(z);
+///
+/// for lane in 0..4 {
+///     assert_relative_eq!(w.extract(lane), lambert_wm1(z.extract(lane)), max_relative = 1e-15);
+/// }
+/// ```
+///
+/// Lanes with arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in [`NAN`](f64::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1_simd;
+/// # use simba::simd::{AutoF64x4, SimdValue};
+/// let w = lambert_wm1_simd(AutoF64x4::from([-1.0, -0.1, 1.0, f64::NAN]));
+///
+/// assert!(w.extract(0).is_nan());
+/// assert!(!w.extract(1).is_nan());
+/// assert!(w.extract(2).is_nan());
+/// assert!(w.extract(3).is_nan());
+/// ```
+#[cfg(feature = "simba")]
+pub fn lambert_wm1_simd<T: simba::simd::SimdRealField<Element = f64>>(z: T) -> T {
+    simd_real::wm1_simd(z)
+}
+
+/// The principal branch of the Lambert W function evaluated on the SIMD types of the [`simba`] crate with `f32` lanes.
+///
+/// Works on any type that implements [`SimdRealField`](simba::simd::SimdRealField) with `f32` lanes,
+/// e.g. the types with eight lanes that fill a 256-bit register. Uses the same sequence of operations as [`lambert_w0_simd`],
+/// except that the Puiseux series is used further from the branch point, since less precision is needed.
+/// The largest relative error found in testing is about 3e-7.
+///
+/// The types of `simba` that are backed by `std::simd` are available with its `portable_simd` feature on nightly.
+///
+/// Only available with the `simba` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0f, lambert_w0f_simd};
+/// use simba::simd::{AutoF32x8, SimdValue};
+///
+/// let z = AutoF32x8::from([-0.3, -0.1, 0.0, 0.5, 1.0, 10.0, 1e20, f32::MAX]);
+/// let w = lambert_w0f_simd(z);
+///
+/// for lane in 0..8 {
+///     assert_relative_eq!(w.extract(lane), lambert_w0f(z.extract(lane)), max_relative = 1e-6);
+/// }
+/// ```
+///
+/// Lanes with arguments smaller than -1/e (≈ -0.36787944) result in [`NAN`](f32::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_w0f_simd;
+/// # use simba::simd::{AutoF32x8, SimdValue};
+/// let w = lambert_w0f_simd(AutoF32x8::splat(-1.0));
+///
+/// assert!(w.extract(0).is_nan());
+/// ```
+#[cfg(feature = "simba")]
+pub fn lambert_w0f_simd<T: simba::simd::SimdRealField<Element = f32>>(z: T) -> T {
+    simd_real::w0_simd(z)
+}
+
+/// The secondary branch of the Lambert W function evaluated on the SIMD types of the [`simba`] crate with `f32` lanes.
+///
+/// Works on any type that implements [`SimdRealField`](simba::simd::SimdRealField) with `f32` lanes.
+/// Uses the same sequence of operations as [`lambert_wm1_simd`],
+/// except that the Puiseux series is used further from the branch point, since less precision is needed.
+/// The largest relative error found in testing is about 3e-7.
+///
+/// Only available with the `simba` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_wm1f, lambert_wm1f_simd};
+/// use simba::simd::{AutoF32x8, SimdValue};
+///
+/// let z = AutoF32x8::from([-0.367, -0.3, -0.2, -0.1, -1e-2, -1e-5, -1e-20, -1e-38]);
+/// let w = lambert_wm1f_simd(z);
+///
+/// for lane in 0..8 {
+///     assert_relative_eq!(w.extract(lane), lambert_wm1f(z.extract(lane)), max_relative = 1e-6);
+/// }
+/// ```
+///
+/// Lanes with arguments smaller than -1/e (≈ -0.36787944) or larger than 0 result in [`NAN`](f32::NAN):
+///
+/// ```
+/// # use lambert_w::lambert_wm1f_simd;
+/// # use simba::simd::{AutoF32x8, SimdValue};
+/// let w = lambert_wm1f_simd(AutoF32x8::from([-1.0, 1.0, f32::NAN, -0.1, -0.1, -0.1, -0.1, -0.1]));
+///
+/// assert!(w.extract(0).is_nan());
+/// assert!(w.extract(1).is_nan());
+/// assert!(w.extract(2).is_nan());
+/// assert!(!w.extract(3).is_nan());
+/// ```
+#[cfg(feature = "simba")]
+pub fn lambert_wm1f_simd<T: simba::simd::SimdRealField<Element = f32>>(z: T) -> T {
+    simd_real::wm1_simd(z)
+}
+
+/// The principal branch of the Lambert W function, computed on `f32`s and rounded once to an [`f16`](half::f16).
+///
+/// Uses [`lambert_w0f`] on the argument converted to an `f32`, which is exact,
+/// and rounds the result to the nearest `f16`.
+///
+/// Only available with the `half` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use half::f16;
+/// use lambert_w::lambert_w0_f16;
+///
+/// let Ω = lambert_w0_f16(f16::ONE);
+///
+/// assert_eq!(Ω, f16::from_f64(0.5671432904097839));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.3679) result in NaN:
+///
+/// ```
+/// # use half::f16;
+/// # use lambert_w::lambert_w0_f16;
+/// assert!(lambert_w0_f16(f16::NEG_ONE).is_nan());
+/// ```
+#[cfg(feature = "half")]
+pub fn lambert_w0_f16(z: half::f16) -> half::f16 {
+    half::f16::from_f32(lambert_w0f(z.to_f32()))
+}
+
+/// The secondary branch of the Lambert W function, computed on `f32`s and rounded once to an [`f16`](half::f16).
+///
+/// Uses [`lambert_wm1f`] on the argument converted to an `f32`, which is exact,
+/// and rounds the result to the nearest `f16`.
+///
+/// Only available with the `half` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use half::f16;
+/// use lambert_w::lambert_wm1_f16;
+///
+/// let w = lambert_wm1_f16(f16::from_f64(-0.25));
+///
+/// assert_eq!(w, f16::from_f64(-2.1532923641103494));
+/// ```
+///
+/// Arguments smaller than -1/e (≈ -0.3679) or larger than 0 result in NaN:
+///
+/// ```
+/// # use half::f16;
+/// # use lambert_w::lambert_wm1_f16;
+/// assert!(lambert_wm1_f16(f16::NEG_ONE).is_nan());
+/// assert!(lambert_wm1_f16(f16::ONE).is_nan());
+/// ```
+#[cfg(feature = "half")]
+pub fn lambert_wm1_f16(z: half::f16) -> half::f16 {
+    half::f16::from_f32(lambert_wm1f(z.to_f32()))
+}
+
+/// The principal branch of the Lambert W function computed to about 100 bits of accuracy in double-double arithmetic.
+///
+/// Computes an initial approximation with [`lambert_w0`], or with [`lambert_w0_near_branch`] close to the branch point,
+/// and then improves it with two steps of Newton's method carried out in double-double arithmetic.
+/// Since the function is ill-conditioned close to the branch point, the accuracy is reduced there,
+/// to about 1e-26 relative error at arguments 1e-12 above -1/e.
+///
+/// Only available with the `dd` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_w0_dd, DoubleDouble};
+///
+/// let Ω = lambert_w0_dd(1.0.into());
+///
+/// // Ω = 0.56714329040978387299996866221035...
+/// assert_eq!(Ω.hi(), 0.5671432904097838);
+/// assert!((Ω.lo() - 3.2888566875211743e-17).abs() < 1e-31);
+/// ```
+///
+/// Arguments smaller than -1/e result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_w0_dd;
+/// assert!(lambert_w0_dd((-1.0).into()).is_nan());
+/// ```
+#[cfg(feature = "dd")]
+pub fn lambert_w0_dd(z: DoubleDouble) -> DoubleDouble {
+    dd::w0_dd(z)
+}
+
+/// The secondary branch of the Lambert W function computed to about 100 bits of accuracy in double-double arithmetic.
+///
+/// Computes an initial approximation with [`lambert_wm1`], or with [`lambert_wm1_near_branch`] close to the branch point,
+/// and then improves it with two steps of Newton's method carried out in double-double arithmetic.
+/// Since the function is ill-conditioned close to the branch point, the accuracy is reduced there,
+/// to about 1e-26 relative error at arguments 1e-12 above -1/e.
+///
+/// Only available with the `dd` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use lambert_w::{lambert_wm1_dd, DoubleDouble};
+///
+/// let w = lambert_wm1_dd((-0.25).into());
+///
+/// // W-1(-1/4) = -2.15329236411034964916909915009298...
+/// assert_eq!(w.hi(), -2.15329236411035);
+/// assert!((w.lo() - 2.0548652470788668e-16).abs() < 1e-31);
+/// ```
+///
+/// Arguments smaller than -1/e, or larger than or equal to 0, result in NaN:
+///
+/// ```
+/// # use lambert_w::lambert_wm1_dd;
+/// assert!(lambert_wm1_dd((-1.0).into()).is_nan());
+/// assert!(lambert_wm1_dd(1.0.into()).is_nan());
+/// ```
+#[cfg(feature = "dd")]
+pub fn lambert_wm1_dd(z: DoubleDouble) -> DoubleDouble {
+    dd::wm1_dd(z)
+}
+
+/// The principal branch of the Lambert W function computed in decimal arithmetic on a [`Decimal`](rust_decimal::Decimal).
+///
+/// Computes an initial approximation with [`lambert_w0`], or with [`lambert_w0_near_branch`] close to the branch point,
+/// and then improves it with steps of Newton's method carried out in decimal arithmetic
+/// until it stops changing. This gives a result that is correct to within a few units in the last decimal place.
+///
+/// Only available with the `rust_decimal` feature.
+///
+/// # Errors
+///
+/// Since a `Decimal` can not be NaN, arguments smaller than -1/e rounded to 28 decimals
+/// result in a [`DomainError`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use core::str::FromStr;
+/// use lambert_w::lambert_w0_decimal;
+/// use rust_decimal::Decimal;
+///
+/// let Ω = lambert_w0_decimal(Decimal::ONE).unwrap();
+///
+/// assert_eq!(Ω, Decimal::from_str("0.5671432904097838729999686622").unwrap());
+/// ```
+///
+/// Arguments smaller than -1/e result in an error:
+///
+/// ```
+/// # use lambert_w::{lambert_w0_decimal, DomainError};
+/// # use rust_decimal::Decimal;
+/// assert_eq!(lambert_w0_decimal(Decimal::NEGATIVE_ONE), Err(DomainError));
+/// ```
+#[cfg(feature = "rust_decimal")]
+pub fn lambert_w0_decimal(z: rust_decimal::Decimal) -> Result<rust_decimal::Decimal, DomainError> {
+    decimal::w0_decimal(z)
+}
+
+/// Enables evaluation of the principal and secondary branches of the Lambert W function
+/// on the types that implement this trait.
+pub trait LambertW {
+    /// The type returned by the Lambert W functions when acting on a value of type `Self`.
+    type Output;
+
+    /// The principal branch of the Lambert W function.
+    fn lambert_w0(self) -> Self::Output;
+
+    /// The secondary branch of the Lambert W function.
     fn lambert_wm1(self) -> Self::Output;
 }
 
@@ -395,3 +2318,243 @@ impl LambertW for f64 {
         lambert_wm1(self)
     }
 }
+
+impl LambertW for i32 {
+    type Output = f64;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// The integer is converted to an `f64`, which is exact.
+    /// Negative integers are smaller than -1/e (≈ -0.36787944117144233), and result in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0(self as f64)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// No integer is in the domain of the secondary branch, so this always results in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1(self as f64)
+    }
+}
+
+impl LambertW for u32 {
+    type Output = f64;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// The integer is converted to an `f64`, which is exact.
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0(self as f64)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// No integer is in the domain of the secondary branch, so this always results in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1(self as f64)
+    }
+}
+
+impl LambertW for i64 {
+    type Output = f64;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// The integer is converted to an `f64`, rounded to the nearest `f64` if its magnitude is larger than 2^53.
+    /// Negative integers are smaller than -1/e (≈ -0.36787944117144233), and result in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0(self as f64)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// No integer is in the domain of the secondary branch, so this always results in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1(self as f64)
+    }
+}
+
+impl LambertW for u64 {
+    type Output = f64;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// The integer is converted to an `f64`, rounded to the nearest `f64` if its magnitude is larger than 2^53.
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0(self as f64)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// No integer is in the domain of the secondary branch, so this always results in [`NAN`](f64::NAN).
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1(self as f64)
+    }
+}
+
+#[cfg(feature = "num-dual")]
+impl LambertW for num_dual::Dual64 {
+    type Output = Self;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the derivative propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) result in NaN.
+    ///
+    /// Delegates to the [`lambert_w0_dual`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0_dual(self)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the derivative propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN.
+    ///
+    /// Delegates to the [`lambert_wm1_dual`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1_dual(self)
+    }
+}
+
+#[cfg(feature = "num-dual")]
+impl LambertW for num_dual::Dual2_64 {
+    type Output = Self;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the first and second derivatives propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) result in NaN.
+    ///
+    /// Delegates to the [`lambert_w0_dual`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0_dual(self)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the first and second derivatives propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN.
+    ///
+    /// Delegates to the [`lambert_wm1_dual`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1_dual(self)
+    }
+}
+
+#[cfg(feature = "num-dual")]
+impl LambertW for num_dual::HyperDual64 {
+    type Output = Self;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the first and second derivatives propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) result in NaN.
+    ///
+    /// Delegates to the [`lambert_w0_dual`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0_dual(self)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy,
+    /// with the first and second derivatives propagated through the function.
+    ///
+    /// Arguments with a real part smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN.
+    ///
+    /// Delegates to the [`lambert_wm1_dual`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1_dual(self)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl LambertW for ordered_float::OrderedFloat<f64> {
+    type Output = Self;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.36787944117144233) result in NaN.
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        Self(lambert_w0(self.0))
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in NaN.
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        Self(lambert_wm1(self.0))
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl LambertW for ordered_float::NotNan<f64> {
+    type Output = Result<Self, DomainError>;
+    /// The principal branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.36787944117144233) result in a [`DomainError`].
+    ///
+    /// Delegates to the [`lambert_w0`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        Self::new(lambert_w0(self.into_inner())).map_err(|_| DomainError)
+    }
+    /// The secondary branch of the Lambert W function evaluated to 50 bits of accuracy.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.36787944117144233) or larger than 0 result in a [`DomainError`].
+    ///
+    /// Delegates to the [`lambert_wm1`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        Self::new(lambert_wm1(self.into_inner())).map_err(|_| DomainError)
+    }
+}
+
+#[cfg(feature = "half")]
+impl LambertW for half::f16 {
+    type Output = Self;
+    /// The principal branch of the Lambert W function.
+    ///
+    /// Evaluated with the approximation with 24-bits of accuracy from the paper on 32-bit floats,
+    /// and rounded to the nearest 16-bit float.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.3679) result in NaN.
+    ///
+    /// Delegates to the [`lambert_w0_f16`] function.
+    #[inline]
+    fn lambert_w0(self) -> Self::Output {
+        lambert_w0_f16(self)
+    }
+    /// The secondary branch of the Lambert W function.
+    ///
+    /// Evaluated with the approximation with 24-bits of accuracy from the paper on 32-bit floats,
+    /// and rounded to the nearest 16-bit float.
+    ///
+    /// Arguments smaller than -1/e (≈ -0.3679) or larger than 0 result in NaN.
+    ///
+    /// Delegates to the [`lambert_wm1_f16`] function.
+    #[inline]
+    fn lambert_wm1(self) -> Self::Output {
+        lambert_wm1_f16(self)
+    }
+}
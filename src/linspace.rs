@@ -0,0 +1,60 @@
+//! An iterator over the principal branch at equally spaced arguments.
+
+use core::iter::FusedIterator;
+
+use crate::lambert_w0;
+
+/// An iterator over `n` equally spaced arguments between `start` and `end`, inclusive,
+/// paired with the principal branch of the Lambert W function at them.
+///
+/// Created by [`lambert_w0_linspace`](crate::lambert_w0_linspace).
+#[derive(Debug, Clone, PartialEq)]
+pub struct W0Linspace {
+    start: f64,
+    end: f64,
+    n: usize,
+    i: usize,
+}
+
+impl W0Linspace {
+    pub(crate) fn new(start: f64, end: f64, n: usize) -> Self {
+        Self {
+            start,
+            end,
+            n,
+            i: 0,
+        }
+    }
+}
+
+impl Iterator for W0Linspace {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.n {
+            return None;
+        }
+
+        // The last argument is exactly `end`.
+        let x = if self.i + 1 == self.n && self.n > 1 {
+            self.end
+        } else {
+            let t = self.i as f64 / (self.n - 1).max(1) as f64;
+            self.start + t * (self.end - self.start)
+        };
+        self.i += 1;
+
+        // Consecutive arguments are usually on the same subdomain of the approximation,
+        // so the branches that select it are predicted correctly.
+        Some((x, lambert_w0(x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.n - self.i;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for W0Linspace {}
+
+impl FusedIterator for W0Linspace {}
@@ -0,0 +1,45 @@
+use crate::{
+    consts::NEG_INV_E,
+    dw0c::dw0c,
+    elementary::{exp, exp_m1},
+    kernel::DefaultKernel,
+};
+
+// This file contains an evaluation of the principal branch of the Lambert W function
+// at -e^(-y) for y >= 1 that never forms the argument -e^(-y) itself.
+// The offset from the branch point, 1/e - e^(-y) = -e^(-1)·(e^(1 - y) - 1),
+// is computed without cancellation, and the result of the 50 bit approximation
+// is then refined with Newton's method on w + e^(-y)·e^(-w) = 0,
+// which converges to full relative accuracy even when the result is tiny.
+// It returns f64::NAN if y is smaller than 1 or NAN.
+
+/// Below this value of y the result is close enough to -1 that the
+/// approximation already has full relative accuracy,
+/// and the Newton iteration is poorly conditioned.
+const REFINE_Y: f64 = 2.0;
+
+pub fn w0_neg_exp(y: f64) -> f64 {
+    if y < 1.0 || y.is_nan() {
+        return f64::NAN;
+    } else if y == 1.0 {
+        // The branch point, where the function value is known exactly.
+        return -1.0;
+    }
+
+    let zc = NEG_INV_E * exp_m1(1.0 - y);
+    let mut w = dw0c::<DefaultKernel>(zc);
+
+    if y >= REFINE_Y {
+        // Kept separate from the exponential of w so that the rounding of y + w
+        // does not limit the relative accuracy of the result.
+        let e = exp(-y);
+        for _ in 0..2 {
+            let t = e * exp(-w);
+            w -= (w + t) / (1.0 - t);
+        }
+        // A final fixed point step keeps the sign of the result negative even when it underflows.
+        w = -e * exp(-w);
+    }
+
+    w
+}
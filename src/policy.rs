@@ -0,0 +1,100 @@
+//! The policies for arguments outside the domain of the functions.
+
+use crate::DomainError;
+
+/// What a function of the crate does with an argument that is outside its domain,
+/// e.g. for [`lambert_w0_with_domain_policy`](crate::lambert_w0_with_domain_policy).
+///
+/// Lets code switch between propagating [`NAN`](f64::NAN), returning a [`Result`] and clamping
+/// by changing a single type, without wrapping every call.
+/// The trait is sealed, so that more policies and methods can be added to it without breaking changes.
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::{lambert_w0_with_domain_policy, ClampPolicy, DomainPolicy, ErrorPolicy, NanPolicy};
+///
+/// fn solve<P: DomainPolicy>(z: f64) -> P::Output {
+///     lambert_w0_with_domain_policy::<P>(z)
+/// }
+///
+/// assert!(solve::<NanPolicy>(-1.0).is_nan());
+/// assert!(solve::<ErrorPolicy>(-1.0).is_err());
+/// assert_eq!(solve::<ClampPolicy>(-1.0), -1.0);
+/// ```
+pub trait DomainPolicy: private::Sealed {
+    /// The type that the functions return under the policy.
+    type Output;
+}
+
+/// Returns [`NAN`](f64::NAN) for arguments outside the domain, like the other functions of the crate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NanPolicy;
+
+impl DomainPolicy for NanPolicy {
+    type Output = f64;
+}
+
+impl private::Sealed for NanPolicy {
+    #[inline(always)]
+    fn eval(f: fn(f64) -> f64, z: f64, _lower: f64, _upper: f64) -> f64 {
+        f(z)
+    }
+}
+
+/// Returns a [`DomainError`] for arguments outside the domain, and for [`NAN`](f64::NAN).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ErrorPolicy;
+
+impl DomainPolicy for ErrorPolicy {
+    type Output = Result<f64, DomainError>;
+}
+
+impl private::Sealed for ErrorPolicy {
+    #[inline(always)]
+    fn eval(f: fn(f64) -> f64, z: f64, _lower: f64, _upper: f64) -> Result<f64, DomainError> {
+        let w = f(z);
+        if w.is_nan() {
+            Err(DomainError)
+        } else {
+            Ok(w)
+        }
+    }
+}
+
+/// Clamps arguments outside the domain to the closest end of it. [`NAN`](f64::NAN) still results in [`NAN`](f64::NAN).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClampPolicy;
+
+impl DomainPolicy for ClampPolicy {
+    type Output = f64;
+}
+
+impl private::Sealed for ClampPolicy {
+    #[inline(always)]
+    fn eval(f: fn(f64) -> f64, z: f64, lower: f64, upper: f64) -> f64 {
+        let w = f(z);
+        if w.is_nan() && !z.is_nan() {
+            f(if z < lower { lower } else { upper })
+        } else {
+            w
+        }
+    }
+}
+
+mod private {
+    /// Prevents implementations of [`DomainPolicy`](super::DomainPolicy) outside of the crate.
+    /// Also holds the evaluation under the policy, so that it is not part of the public API.
+    pub trait Sealed {
+        /// Evaluates the branch `f` of the Lambert W function at `z`, and handles the argument according to the policy
+        /// if the result is [`NAN`](f64::NAN). The domain of the branch is the interval from `lower` to `upper`.
+        fn eval(
+            f: fn(f64) -> f64,
+            z: f64,
+            lower: f64,
+            upper: f64,
+        ) -> <Self as super::DomainPolicy>::Output
+        where
+            Self: super::DomainPolicy;
+    }
+}
@@ -0,0 +1,45 @@
+//! The Puiseux series of the Lambert W function around its branch point at -1/e.
+
+use crate::elementary::sqrt;
+
+/// The largest value of p = sqrt(2(ez + 1)) for which [`w_plus_one`] is accurate to machine precision.
+/// The first omitted term is smaller than 2e-18 relative to the sum.
+pub const MAX_P: f64 = 0.15;
+
+/// The coefficients of p, p^2, ..., p^17 in the series W = -1 + p - p^2/3 + 11p^3/72 - ...
+pub const COEFFICIENTS: [f64; 17] = [
+    1.0,                           // 1
+    -0.333_333_333_333_333_3,      // -1/3
+    0.152_777_777_777_777_8,       // 11/72
+    -0.079_629_629_629_629_63,     // -43/540
+    0.044_502_314_814_814_814,     // 769/17280
+    -0.025_984_714_873_603_76,     // -221/8505
+    0.015_635_632_532_333_92,      // 680863/43545600
+    -0.009_616_892_024_299_432,    // -1963/204120
+    0.006_014_543_252_956_118,     // 226287557/37623398400
+    -0.003_811_298_034_891_999_3,  // -5776369/1515591000
+    0.002_440_877_991_143_982_6,   // 169709463197/69528040243200
+    -0.001_576_930_344_686_784_1,  // -1118511313/709296588000
+    0.001_026_263_320_507_607_1,   // 667874164916771/650782456676352000
+    -0.000_672_061_631_156_136_2,  // -500525573/744761417400
+    0.000_442_473_061_814_620_9,   // 103663334225097487/234281684403486720000
+    -0.000_292_677_224_729_627_46, // -466901817532379/1595278956070800000
+    0.000_194_387_276_054_539_33,  // 21235294185086305043/109242202556140093440000
+];
+
+/// Computes p = sqrt(2(ez + 1)) = sqrt(2e·zc) from zc = z + 1/e.
+#[inline(always)]
+pub fn p_from_zc(zc: f64) -> f64 {
+    sqrt(2.0 * core::f64::consts::E * zc)
+}
+
+/// Evaluates W + 1 with the truncated Puiseux series.
+///
+/// A positive `p` gives the principal branch and a negative `p` gives the secondary branch.
+/// Only accurate for |p| <= [`MAX_P`].
+pub fn w_plus_one(p: f64) -> f64 {
+    p * COEFFICIENTS
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &coefficient| coefficient + p * acc)
+}
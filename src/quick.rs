@@ -0,0 +1,40 @@
+use crate::elementary::ln_1p;
+
+// This is an implementation of a low-accuracy approximation of the principal
+// branch of the Lambert W function for non-negative inputs.
+// It evaluates L·P(L)/Q(L), where L = ln(1 + z) and P and Q are polynomials of degree 4
+// whose coefficients were fitted to minimize the largest relative error of the result.
+// It returns f64::NAN if the input is negative or NAN,
+// and returns the input unchanged if it is 0 or f64::INFINITY.
+
+/// The coefficients of P(L) in order of increasing degree.
+const NUMERATOR: [f64; 5] = [
+    1.0,
+    1.302_018_170_933_679_6,
+    0.321_460_761_588_649_9,
+    0.078_411_249_045_312_08,
+    0.001_273_113_239_112_229_6,
+];
+
+/// The coefficients of Q(L) in order of increasing degree.
+const DENOMINATOR: [f64; 5] = [
+    1.0,
+    1.803_165_827_604_778_4,
+    0.556_039_259_532_297_9,
+    0.085_794_996_063_182_55,
+    0.001_275_357_191_386_864_2,
+];
+
+pub fn w0_quick(z: f64) -> f64 {
+    if !(z > 0.0 && z < f64::INFINITY) {
+        return if z >= 0.0 { z } else { f64::NAN };
+    }
+
+    let l = ln_1p(z);
+    let [n0, n1, n2, n3, n4] = NUMERATOR;
+    let [d0, d1, d2, d3, d4] = DENOMINATOR;
+    let n = n0 + l * (n1 + l * (n2 + l * (n3 + l * n4)));
+    let d = d0 + l * (d1 + l * (d2 + l * (d3 + l * d4)));
+
+    l * n / d
+}
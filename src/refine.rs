@@ -0,0 +1,57 @@
+//! Iteration steps that improve an approximation of the Lambert W function.
+
+use crate::elementary::{exp, ln};
+
+/// Performs one step of Halley's method on f(w) = w·e^w - z.
+///
+/// Has cubic convergence. Returns `w` unchanged if it already solves the equation exactly.
+pub fn halley(z: f64, w: f64) -> f64 {
+    let ew = exp(w);
+    let f = w * ew - z;
+    if f == 0.0 {
+        return w;
+    }
+    let wp1 = w + 1.0;
+    w - f / (ew * wp1 - (w + 2.0) * f / (2.0 * wp1))
+}
+
+/// Performs one step of the iteration by Fritsch, Shafer and Crowley.
+///
+/// Has quartic convergence and works with the logarithm of z/w instead of the exponential of w,
+/// so it does not overflow for large function values.
+/// Contains no branches, so it can be used in branch-free code.
+#[inline(always)]
+pub fn fritsch(z: f64, w: f64) -> f64 {
+    let zn = ln(z / w) - w;
+    let wp1 = w + 1.0;
+    let q = 2.0 * wp1 * (wp1 + 2.0 / 3.0 * zn);
+    w * (1.0 + zn / wp1 * (q - zn) / (q - 2.0 * zn))
+}
+
+/// Performs one step of [`fritsch`], but returns `w` unchanged if it already solves the equation exactly.
+///
+/// Without this check the step is 0/0 at the branch point w = -1, even though -1 solves the equation there.
+pub fn fritsch_unless_solved(z: f64, w: f64) -> f64 {
+    if ln(z / w) - w == 0.0 {
+        w
+    } else {
+        fritsch(z, w)
+    }
+}
+
+/// Performs one step of [`fritsch`], unless `w` is 0, -1 or not finite.
+///
+/// The approximations are exact at 0 and -1, and the iteration is undefined there and at infinity.
+/// Returns `w` unchanged if the step is not finite, which happens when z/w underflows for subnormal `z`.
+#[inline(always)]
+pub fn fritsch_checked(z: f64, w: f64) -> f64 {
+    if w == 0.0 || w == -1.0 || !w.is_finite() {
+        return w;
+    }
+    let next = fritsch(z, w);
+    if next.is_finite() {
+        next
+    } else {
+        w
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{elementary::exp, lambert_w0, refine::halley};
+
+// This file contains an evaluation of the principal branch of the Lambert W function
+// that walks the result of the 50 bit approximation to the float whose
+// w·e^w, evaluated in f64, is closest to the input.
+
+/// The largest number of ulps the result is moved from the initial approximation.
+/// The refined approximation is only a few ulps away from the correct result,
+/// so this is only reached close to the branch point, where w·e^w is flat.
+const MAX_STEPS: usize = 64;
+
+/// Returns the smallest float larger than `x`.
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Returns the largest float smaller than `x`.
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+pub fn w0_roundtrip(z: f64) -> f64 {
+    let w0 = lambert_w0(z);
+    if !w0.is_finite() {
+        return w0;
+    }
+
+    // The approximation only has 50 bits of absolute accuracy, so it can be many ulps away from
+    // the correct result when that is close to zero. The first Halley step brings the absolute error
+    // down to the square of that, and the second one makes the relative error small.
+    let mut w = halley(z, halley(z, w0));
+    if !w.is_finite() {
+        w = w0;
+    }
+
+    let residual = |w: f64| w * exp(w) - z;
+
+    let mut r = residual(w);
+    // w·e^w is increasing on the principal branch, so we walk upwards if it is too small.
+    let upwards = r < 0.0;
+    let mut best = w;
+    let mut best_abs_r = r.abs();
+
+    for _ in 0..MAX_STEPS {
+        if r == 0.0 {
+            return w;
+        }
+
+        w = if upwards { next_up(w) } else { next_down(w) };
+        r = residual(w);
+
+        if r.abs() < best_abs_r {
+            best = w;
+            best_abs_r = r.abs();
+        }
+
+        if (r < 0.0) != upwards && r != 0.0 {
+            // We stepped past z without hitting it.
+            break;
+        }
+    }
+
+    // Rounding errors in the evaluation of w·e^w make it slightly non-monotonic,
+    // so we make sure that no neighbour of the best candidate is better.
+    for _ in 0..MAX_STEPS {
+        if best_abs_r == 0.0 {
+            break;
+        }
+
+        let mut improved = false;
+        for candidate in [next_up(best), next_down(best)] {
+            let abs_r = residual(candidate).abs();
+            if abs_r < best_abs_r {
+                best = candidate;
+                best_abs_r = abs_r;
+                improved = true;
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    best
+}
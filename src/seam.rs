@@ -0,0 +1,365 @@
+//! Opt-in corrections that make the 50-bit approximations continuous and monotone across the seams between their subdomains.
+//!
+//! The rational functions that approximate the function on neighboring subdomains do not take
+//! exactly the same value at the seam between them. The difference is a few ulps, but shows up as a
+//! step when the function is differenced across the seam. To remove the step, the approximation on the
+//! subdomain after a seam is shifted by the difference at the seam, and the shift is reduced linearly to zero
+//! over a transition zone that covers the first 1/1024 of the subdomain, in terms of the transformed input
+//! of its rational function. Outside the transition zone the approximations are unchanged.
+//!
+//! Rounding errors can still make the function go the wrong way by a few ulps between the last input of a
+//! subdomain and the first input of the next. To prevent that, the approximation on each subdomain is clamped
+//! to the range between the values of the approximations at its seams.
+//!
+//! The corrections cost an extra comparison per subdomain and an extra square root or logarithm,
+//! so they are only applied by [`lambert_w0_smooth`](crate::lambert_w0_smooth) and [`lambert_wm1_smooth`](crate::lambert_wm1_smooth).
+
+use crate::{
+    consts::{INV_SQRT_E, NEG_INV_E},
+    dw0c::dw0c,
+    dwm1c::dwm1c,
+    kernel::MathKernel,
+};
+
+/// The correction of the approximation on a subdomain.
+struct Seam {
+    /// The end of the subdomain, in terms of z + 1/e for the principal branch and of z for the secondary branch.
+    bound: f64,
+    /// The transformed input where the transition zone ends.
+    x_end: f64,
+    /// The reciprocal of the signed width of the transition zone.
+    inv_width: f64,
+    /// The value of the approximation of the previous subdomain minus the value of this one at the seam.
+    step: f64,
+    /// The values of the approximations at the seams of the subdomain, in increasing order.
+    range: [f64; 2],
+}
+
+impl Seam {
+    const fn new(bound: f64, x_end: f64, inv_width: f64, step: f64, range: [f64; 2]) -> Self {
+        Self {
+            bound,
+            x_end,
+            inv_width,
+            step,
+            range,
+        }
+    }
+
+    /// The first subdomain of a branch, which has no seam before it and is therefore only clamped.
+    const fn first(bound: f64, range: [f64; 2]) -> Self {
+        Self::new(bound, 0.0, 0.0, 0.0, range)
+    }
+
+    /// Corrects the value `w` of the approximation on the subdomain at the transformed input `x`.
+    #[inline(always)]
+    fn apply(&self, w: f64, x: f64) -> f64 {
+        clamp(
+            w + seam_correction(x, self.x_end, self.inv_width, self.step),
+            self.range[0],
+            self.range[1],
+        )
+    }
+}
+
+/// The corrections of the subdomains of the principal branch, in the order that [`dw0c`] checks them.
+const W0_SEAMS: [Seam; 19] = [
+    // X_1
+    Seam::first(
+        2.549_893_906_503_473_6,
+        [f64::NEG_INFINITY, 0.893_196_827_565_503_9],
+    ),
+    // X_2
+    Seam::new(
+        43.613_924_462_669_37,
+        1.601_728_610_650_183,
+        204.503_665_995_990_4,
+        7.736_740_240_544_696e-21,
+        [0.893_196_827_565_503_9, 2.753_890_973_500_704_7],
+    ),
+    // X_3
+    Seam::new(
+        598.453_533_718_782_8,
+        6.621_524_573_465_754,
+        57.337_311_171_498_56,
+        -3.480_704_247_017_999e-16,
+        [2.753_890_973_500_704_7, 4.820_795_104_395_193],
+    ),
+    // X_4
+    Seam::new(
+        8_049.491_985_075_761_5,
+        24.527_036_172_033_15,
+        15.692_127_525_946_935,
+        -1.217_998_612_638_869_6e-15,
+        [4.820_795_104_395_193, 7.041_497_672_608_328],
+    ),
+    // X_5
+    Seam::new(
+        111_124.954_121_217_82,
+        89.956_886_061_052_27,
+        4.203_006_251_408_916,
+        -1.219_255_152_281_512_8e-15,
+        [7.041_497_672_608_328, 9.379_844_106_361_54],
+    ),
+    // X_6
+    Seam::new(
+        1.587_042_981_208_229_7e6,
+        334.258_808_963_703_53,
+        1.105_324_416_121_836_7,
+        -2.344_412_813_819_544_3e-15,
+        [9.379_844_106_361_54, 11.808_558_302_214_953],
+    ),
+    // X_7
+    Seam::new(
+        2.341_470_840_187_546e7,
+        1_263.274_152_111_034_7,
+        0.286_105_784_225_254_84,
+        -1.792_498_253_770_944_2e-15,
+        [11.808_558_302_214_953, 14.308_052_444_545_249],
+    ),
+    // X_8
+    Seam::new(
+        3.557_647_430_800_996_4e8,
+        4_852.568_895_723_902,
+        0.073_023_660_445_528_94,
+        -4.458_032_453_976_735e-15,
+        [14.308_052_444_545_249, 16.864_565_528_815_51],
+    ),
+    // X_9
+    Seam::new(
+        5.550_171_629_616_363e9,
+        18_916.060_675_292_443,
+        0.018_404_771_107_008_2,
+        -3.794_425_777_733_505e-15,
+        [16.864_565_528_815_51, 19.468_306_835_547_974],
+    ),
+    // X_10
+    Seam::new(
+        8.867_470_483_965_778e10,
+        74_717.524_323_945_59,
+        0.004_586_097_762_657_392,
+        -4.867_150_761_069_189e-15,
+        [19.468_306_835_547_974, 22.112_114_866_694_153],
+    ),
+    // X_11
+    Seam::new(
+        1.447_779_186_527_290_3e12,
+        298_667.215_192_077_16,
+        0.001_130_924_402_128_157_5,
+        -4.575_673_303_924_860_5e-15,
+        [22.112_114_866_694_153, 24.790_587_844_054_09],
+    ),
+    // X_12
+    Seam::new(
+        2.411_145_863_251_185e13,
+        1_206_857.181_689_941_8,
+        0.000_276_226_324_141_345_46,
+        -6.209_409_389_348_352e-15,
+        [24.790_587_844_054_09, 27.499_539_062_201_33],
+    ),
+    // X_13
+    Seam::new(
+        4.089_703_644_260_084_4e14,
+        4_925_295.790_605_598,
+        6.687_271_079_342_102e-5,
+        -7.773_544_012_827_217e-15,
+        [27.499_539_062_201_33, 30.235_642_371_281_063],
+    ),
+    // X_14
+    Seam::new(
+        7.055_590_147_678_997e15,
+        20_285_295.539_238_83,
+        1.605_656_309_281_674e-5,
+        -6.370_850_357_709_723e-15,
+        [30.235_642_371_281_063, 32.996_204_098_742_24],
+    ),
+    // X_15
+    Seam::new(
+        1.236_660_755_797_672_8e17,
+        84_258_951.301_419_48,
+        3.825_687_389_817_13e-6,
+        -7.917_594_987_248_035e-15,
+        [32.996_204_098_742_24, 35.779_000_252_974_3],
+    ),
+    // X_16
+    Seam::new(
+        2.199_937_348_793_1e18,
+        352_766_912.619_652_87,
+        9.049_480_236_396_639e-7,
+        -7.405_071_079_034_558e-15,
+        [35.779_000_252_974_3, 38.582_170_295_710_07],
+    ),
+    // X_17
+    Seam::new(
+        3.968_539_219_834_401_6e19,
+        1_487_922_108.521_242,
+        2.126_062_327_166_737_7e-7,
+        -8.329_906_554_348_471e-15,
+        [38.582_170_295_710_07, 41.404_134_105_700_905],
+    ),
+    // U_18
+    Seam::new(
+        1.412_707_514_527_465_2e104,
+        45.317_638_709_209_86,
+        5.259_728_827_331_259,
+        -2.892_608_184_652_503e-15,
+        [41.404_134_105_700_905, 234.357_509_987_680_8],
+    ),
+    // U_19
+    Seam::new(
+        f64::MAX,
+        240.273_311_229_380_68,
+        2.178_870_106_486_372_5,
+        6.416_285_537_085_707e-14,
+        [234.357_509_987_680_8, f64::INFINITY],
+    ),
+];
+
+/// The corrections of the subdomains of the secondary branch, in the order that [`dwm1c`] checks them.
+const WM1_SEAMS: [Seam; 11] = [
+    // X_-1
+    Seam::first(-0.354_291_330_944_216_4, [-1.3, f64::INFINITY]),
+    // Y_-1
+    Seam::new(
+        -0.188_726_882_822_894_35,
+        0.489_663_102_011_672_8,
+        -3_338.809_751_825_954,
+        -5.390_187_156_963_738_6e-17,
+        [-2.637_155_093_464_933, -1.3],
+    ),
+    // Y_-2
+    Seam::new(
+        -0.060_497_597_226_958_34,
+        0.183_138_406_699_981_72,
+        -7_807.490_077_942_495,
+        5.353_251_616_136_935e-16,
+        [-4.252_707_459_358_246, -2.637_155_093_464_933],
+    ),
+    // Y_-3
+    Seam::new(
+        -0.017_105_334_740_676_01,
+        0.052_073_427_688_810_62,
+        -27_060.181_645_018_18,
+        1.178_906_972_173_100_8e-15,
+        [-5.831_667_947_830_436, -4.252_707_459_358_246],
+    ),
+    // Y_-4
+    Seam::new(
+        -0.004_595_496_212_794_371,
+        0.014_258_580_215_027_907,
+        -97_816.776_460_737_31,
+        1.333_375_446_787_919_7e-15,
+        [-7.381_679_762_021_359, -5.831_667_947_830_436],
+    ),
+    // Y_-5
+    Seam::new(
+        -0.001_200_161_067_219_772_4,
+        0.003_797_507_304_625_469_7,
+        -364_402.729_785_390_7,
+        2.230_241_835_825_685_5e-15,
+        [-8.912_786_431_964_98, -7.381_679_762_021_359],
+    ),
+    // Y_-6
+    Seam::new(
+        -0.000_307_288_059_321_915,
+        0.000_989_454_243_608_634,
+        -1_389_784.876_691_550_7,
+        1.147_440_861_745_433_3e-15,
+        [-10.432_666_875_410_444, -8.912_786_431_964_98],
+    ),
+    // Y_-7
+    Seam::new(
+        -0.000_077_447_159_838_062_18,
+        0.000_253_184_020_948_538_1,
+        -5_403_085.194_258_392,
+        2.062_365_139_186_484e-15,
+        [-11.946_339_583_100_333, -10.432_666_875_410_444],
+    ),
+    // V_-8
+    Seam::new(
+        -4.580_811_969_815_817_5e-17,
+        -9.493_410_908_435_028,
+        -36.368_601_336_619_78,
+        2.698_507_994_645_315e-15,
+        [-41.343_997_561_683_83, -11.946_339_583_100_333],
+    ),
+    // V_-9
+    Seam::new(
+        -6.107_367_223_659_479e-79,
+        -37.761_203_765_869_14,
+        -7.187_344_062_731_335,
+        9.077_698_734_632_707e-15,
+        [-185.316_793_319_420_5, -41.343_997_561_683_83],
+    ),
+    // V_-10
+    Seam::new(
+        -5e-324,
+        -180.645_845_063_827_04,
+        -1.814_491_797_303_321_2,
+        5.463_481_207_983_855e-14,
+        [f64::NEG_INFINITY, -185.316_793_319_420_5],
+    ),
+];
+
+/// Like [`dw0c`], but with the corrections at the seams between the subdomains.
+pub fn dw0c_smooth<K: MathKernel>(zc: f64) -> f64 {
+    let w = dw0c::<K>(zc);
+    match W0_SEAMS.iter().position(|seam| zc <= seam.bound) {
+        // The approximations on the last two subdomains are functions of ln(zc), the others of sqrt(zc).
+        Some(i) if w.is_finite() => {
+            let x = if i < 17 { K::sqrt(zc) } else { K::ln(zc) };
+            W0_SEAMS[i].apply(w, x)
+        }
+        _ => w,
+    }
+}
+
+/// Like [`dwm1c`], but with the corrections at the seams between the subdomains.
+pub fn dwm1c_smooth<K: MathKernel>(z: f64, zc: f64) -> f64 {
+    let w = dwm1c::<K>(z, zc);
+    match WM1_SEAMS.iter().position(|seam| z <= seam.bound) {
+        // The approximation on the first subdomain is a function of sqrt(zc),
+        // those on the next seven of -z / (1/sqrt(e) + sqrt(zc)), and the others of ln(-z).
+        Some(i) if w.is_finite() => {
+            let x = if i == 0 {
+                K::sqrt(zc)
+            } else if i < 8 {
+                -z / (INV_SQRT_E + K::sqrt(z - NEG_INV_E))
+            } else {
+                K::ln(-z)
+            };
+            WM1_SEAMS[i].apply(w, x)
+        }
+        _ => w,
+    }
+}
+
+/// Returns the correction to add to the approximation of a subdomain at the transformed input `x`.
+///
+/// `step` is the value of the approximation of the previous subdomain minus the value of this one at the seam,
+/// `x_end` is the transformed input where the transition zone ends, and `inv_width` is the reciprocal of
+/// the signed width of the transition zone.
+#[inline(always)]
+fn seam_correction(x: f64, x_end: f64, inv_width: f64, step: f64) -> f64 {
+    let t = (x_end - x) * inv_width;
+    if t > 0.0 {
+        step * t
+    } else {
+        0.0
+    }
+}
+
+/// Restricts `w` to the interval from `lo` to `hi`.
+///
+/// Unlike [`f64::clamp`] this does not handle [`NAN`](f64::NAN), which the approximations never return,
+/// and is therefore cheaper.
+#[inline(always)]
+fn clamp(w: f64, lo: f64, hi: f64) -> f64 {
+    if w < lo {
+        lo
+    } else if w > hi {
+        hi
+    } else {
+        w
+    }
+}
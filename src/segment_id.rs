@@ -0,0 +1,211 @@
+//! The names of the subdomains of the approximations with 50 bits of accuracy.
+
+use crate::{offset_from_branch_point, Branch, NEG_INV_E, W0_SERIES_LIMIT};
+
+/// A subdomain of the approximations with 50 bits of accuracy, on which the function is approximated
+/// by its own rational function, named as in Fukushima's paper.
+///
+/// Returned by [`lambert_w0_segment`](crate::lambert_w0_segment) and [`lambert_wm1_segment`](crate::lambert_wm1_segment),
+/// which let users find out which approximation is used for an argument, e.g. when diagnosing its accuracy
+/// or profiling which approximations a workload uses.
+///
+/// # Example
+///
+/// ```
+/// use lambert_w::{lambert_w0_segment, Branch, SegmentId};
+///
+/// let segment = lambert_w0_segment(1e300).unwrap();
+///
+/// assert_eq!(segment, SegmentId::U19);
+/// assert_eq!(segment.name(), "U_19");
+/// assert_eq!(segment.branch(), Branch::W0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SegmentId {
+    /// The subdomain of the principal branch where W ≤ 0.893.
+    X1,
+    /// The subdomain of the principal branch where 0.893 < W ≤ 2.754.
+    X2,
+    /// The subdomain of the principal branch where 2.754 < W ≤ 4.821.
+    X3,
+    /// The subdomain of the principal branch where 4.821 < W ≤ 7.041.
+    X4,
+    /// The subdomain of the principal branch where 7.041 < W ≤ 9.380.
+    X5,
+    /// The subdomain of the principal branch where 9.380 < W ≤ 11.809.
+    X6,
+    /// The subdomain of the principal branch where 11.809 < W ≤ 14.308.
+    X7,
+    /// The subdomain of the principal branch where 14.308 < W ≤ 16.865.
+    X8,
+    /// The subdomain of the principal branch where 16.865 < W ≤ 19.468.
+    X9,
+    /// The subdomain of the principal branch where 19.468 < W ≤ 22.112.
+    X10,
+    /// The subdomain of the principal branch where 22.112 < W ≤ 24.791.
+    X11,
+    /// The subdomain of the principal branch where 24.791 < W ≤ 27.500.
+    X12,
+    /// The subdomain of the principal branch where 27.500 < W ≤ 30.236.
+    X13,
+    /// The subdomain of the principal branch where 30.236 < W ≤ 32.996.
+    X14,
+    /// The subdomain of the principal branch where 32.996 < W ≤ 35.779.
+    X15,
+    /// The subdomain of the principal branch where 35.779 < W ≤ 38.582.
+    X16,
+    /// The subdomain of the principal branch where 38.582 < W ≤ 41.404.
+    X17,
+    /// The subdomain of the principal branch where 41.404 < W ≤ 234.358.
+    U18,
+    /// The subdomain of the principal branch where W > 234.358.
+    U19,
+    /// The subdomain of the secondary branch where W ≥ -1.3.
+    Xm1,
+    /// The subdomain of the secondary branch where -1.3 > W ≥ -2.637.
+    Ym1,
+    /// The subdomain of the secondary branch where -2.637 > W ≥ -4.253.
+    Ym2,
+    /// The subdomain of the secondary branch where -4.253 > W ≥ -5.832.
+    Ym3,
+    /// The subdomain of the secondary branch where -5.832 > W ≥ -7.382.
+    Ym4,
+    /// The subdomain of the secondary branch where -7.382 > W ≥ -8.913.
+    Ym5,
+    /// The subdomain of the secondary branch where -8.913 > W ≥ -10.433.
+    Ym6,
+    /// The subdomain of the secondary branch where -10.433 > W ≥ -11.946.
+    Ym7,
+    /// The subdomain of the secondary branch where -11.946 > W ≥ -41.344.
+    Vm8,
+    /// The subdomain of the secondary branch where -41.344 > W ≥ -185.316.
+    Vm9,
+    /// The subdomain of the secondary branch where W < -185.316.
+    Vm10,
+}
+
+/// The subdomains of the principal branch with the largest z + 1/e in each of them,
+/// in the order that the approximation with 50 bits of accuracy checks them.
+const W0_SEGMENTS: [(f64, SegmentId); 19] = [
+    (2.549_893_906_503_473_6, SegmentId::X1),
+    (43.613_924_462_669_37, SegmentId::X2),
+    (598.453_533_718_782_8, SegmentId::X3),
+    (8_049.491_985_075_761_5, SegmentId::X4),
+    (111_124.954_121_217_82, SegmentId::X5),
+    (1.587_042_981_208_229_7e6, SegmentId::X6),
+    (2.341_470_840_187_546e7, SegmentId::X7),
+    (3.557_647_430_800_996_4e8, SegmentId::X8),
+    (5.550_171_629_616_363e9, SegmentId::X9),
+    (8.867_470_483_965_778e10, SegmentId::X10),
+    (1.447_779_186_527_290_3e12, SegmentId::X11),
+    (2.411_145_863_251_185e13, SegmentId::X12),
+    (4.089_703_644_260_084_4e14, SegmentId::X13),
+    (7.055_590_147_678_997e15, SegmentId::X14),
+    (1.236_660_755_797_672_8e17, SegmentId::X15),
+    (2.199_937_348_793_1e18, SegmentId::X16),
+    (3.968_539_219_834_401_6e19, SegmentId::X17),
+    (1.412_707_514_527_465_2e104, SegmentId::U18),
+    (f64::MAX, SegmentId::U19),
+];
+
+/// The subdomains of the secondary branch with the largest z in each of them,
+/// in the order that the approximation with 50 bits of accuracy checks them.
+const WM1_SEGMENTS: [(f64, SegmentId); 11] = [
+    (-0.354_291_330_944_216_4, SegmentId::Xm1),
+    (-0.188_726_882_822_894_35, SegmentId::Ym1),
+    (-0.060_497_597_226_958_34, SegmentId::Ym2),
+    (-0.017_105_334_740_676_01, SegmentId::Ym3),
+    (-0.004_595_496_212_794_371, SegmentId::Ym4),
+    (-0.001_200_161_067_219_772_4, SegmentId::Ym5),
+    (-0.000_307_288_059_321_915, SegmentId::Ym6),
+    (-0.000_077_447_159_838_062_18, SegmentId::Ym7),
+    (-4.580_811_969_815_817_5e-17, SegmentId::Vm8),
+    (-6.107_367_223_659_479e-79, SegmentId::Vm9),
+    (-5e-324, SegmentId::Vm10),
+];
+
+impl SegmentId {
+    /// Returns the name of the subdomain in Fukushima's paper, e.g. `"X_1"` or `"V_-10"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::X1 => "X_1",
+            Self::X2 => "X_2",
+            Self::X3 => "X_3",
+            Self::X4 => "X_4",
+            Self::X5 => "X_5",
+            Self::X6 => "X_6",
+            Self::X7 => "X_7",
+            Self::X8 => "X_8",
+            Self::X9 => "X_9",
+            Self::X10 => "X_10",
+            Self::X11 => "X_11",
+            Self::X12 => "X_12",
+            Self::X13 => "X_13",
+            Self::X14 => "X_14",
+            Self::X15 => "X_15",
+            Self::X16 => "X_16",
+            Self::X17 => "X_17",
+            Self::U18 => "U_18",
+            Self::U19 => "U_19",
+            Self::Xm1 => "X_-1",
+            Self::Ym1 => "Y_-1",
+            Self::Ym2 => "Y_-2",
+            Self::Ym3 => "Y_-3",
+            Self::Ym4 => "Y_-4",
+            Self::Ym5 => "Y_-5",
+            Self::Ym6 => "Y_-6",
+            Self::Ym7 => "Y_-7",
+            Self::Vm8 => "V_-8",
+            Self::Vm9 => "V_-9",
+            Self::Vm10 => "V_-10",
+        }
+    }
+
+    /// Returns the branch that the subdomain belongs to.
+    pub const fn branch(self) -> Branch {
+        match self {
+            Self::Xm1
+            | Self::Ym1
+            | Self::Ym2
+            | Self::Ym3
+            | Self::Ym4
+            | Self::Ym5
+            | Self::Ym6
+            | Self::Ym7
+            | Self::Vm8
+            | Self::Vm9
+            | Self::Vm10 => Branch::Wm1,
+            _ => Branch::W0,
+        }
+    }
+}
+
+/// Returns the subdomain whose approximation [`lambert_w0`](crate::lambert_w0) evaluates at `z`,
+/// or `None` if it uses none of them.
+pub fn w0_segment(z: f64) -> Option<SegmentId> {
+    // The same special cases as in `lambert_w0`, where the value is known exactly or given by a series.
+    if z == 1.0 || z == core::f64::consts::E || z == NEG_INV_E || z.abs() < W0_SERIES_LIMIT {
+        return None;
+    }
+    let zc = offset_from_branch_point(z);
+    if zc < 0.0 {
+        return None;
+    }
+    W0_SEGMENTS
+        .iter()
+        .find(|(bound, _)| zc <= *bound)
+        .map(|&(_, segment)| segment)
+}
+
+/// Returns the subdomain whose approximation [`lambert_wm1`](crate::lambert_wm1) evaluates at `z`,
+/// or `None` if it uses none of them.
+pub fn wm1_segment(z: f64) -> Option<SegmentId> {
+    if offset_from_branch_point(z) < 0.0 {
+        return None;
+    }
+    WM1_SEGMENTS
+        .iter()
+        .find(|(bound, _)| z <= *bound)
+        .map(|&(_, segment)| segment)
+}
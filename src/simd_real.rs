@@ -0,0 +1,144 @@
+//! Evaluation of the branches of the Lambert W function on the SIMD types of the `simba` crate.
+
+use simba::simd::SimdRealField;
+
+use crate::{
+    consts::{NEG_INV_E, NEG_INV_E_F32, NEG_INV_E_LO},
+    puiseux::{COEFFICIENTS, MAX_P},
+};
+
+// The functions in this file can not branch on the value of the input, since the lanes
+// of a SIMD value can lie in different parts of the domain. Instead they evaluate every part of the
+// algorithm on all lanes, and combine the results of the lanes with blends.
+//
+// Close to the branch point the result is the Puiseux series around it, which is accurate to the precision of the lanes for |p| <= Lane::MAX_P.
+// Further away the series, or a global approximation of the branch, is used as the initial value of
+// two steps of the iteration by Fritsch, Shafer and Crowley, which has quartic convergence.
+// The functions are generic over the type of the lanes, and the constants are rounded to that type.
+
+/// The properties of the type of the lanes that the evaluation depends on.
+pub trait Lane {
+    /// The value of the type that is closest to -1/e, which is treated as the branch point.
+    const NEG_INV_E: f64;
+    /// The difference between -1/e and [`Lane::NEG_INV_E`].
+    const NEG_INV_E_LO: f64;
+    /// The largest value of |p| for which the Puiseux series is accurate to the precision of the type.
+    const MAX_P: f64;
+}
+
+impl Lane for f64 {
+    const NEG_INV_E: f64 = NEG_INV_E;
+    const NEG_INV_E_LO: f64 = NEG_INV_E_LO;
+    const MAX_P: f64 = MAX_P;
+}
+
+impl Lane for f32 {
+    // Widening an f32 to an f64 is exact, so the difference is computed with a single rounding.
+    const NEG_INV_E: f64 = NEG_INV_E_F32 as f64;
+    const NEG_INV_E_LO: f64 = (NEG_INV_E - NEG_INV_E_F32 as f64) + NEG_INV_E_LO;
+    const MAX_P: f64 = 0.5;
+}
+
+/// Converts `x` to a SIMD value with all lanes equal to `x` rounded to the type of the lanes.
+#[inline(always)]
+fn splat<T: SimdRealField>(x: f64) -> T {
+    T::from_subset(&x)
+}
+
+/// Below this value of |p| the Puiseux series is a good initial value for the iteration.
+/// The series converges for |p| < sqrt(2).
+const SERIES_START_P: f64 = 1.0;
+
+/// Computes zc = z + 1/e with -1/e represented as the sum of two floats, like the functions that act on `f64`s.
+/// The result is clamped to zero, so that the argument closest to -1/e is treated as the branch point.
+fn offset_from_branch_point<T: SimdRealField>(z: T) -> T
+where
+    T::Element: Lane,
+{
+    ((z - splat::<T>(T::Element::NEG_INV_E)) - splat::<T>(T::Element::NEG_INV_E_LO))
+        .simd_max(splat::<T>(0.0))
+}
+
+/// Evaluates W + 1 with the truncated Puiseux series in p = ±sqrt(2e·zc).
+fn puiseux<T: SimdRealField>(p: T) -> T {
+    let sum = COEFFICIENTS
+        .iter()
+        .rev()
+        .fold(splat::<T>(0.0), |acc, &coefficient| {
+            splat::<T>(coefficient) + p.clone() * acc
+        });
+    p * sum
+}
+
+/// Performs one step of the iteration by Fritsch, Shafer and Crowley, given the logarithm of z/w.
+fn fritsch<T: SimdRealField>(w: T, ln_z_over_w: T) -> T {
+    let zn = ln_z_over_w - w.clone();
+    let wp1 = w.clone() + splat::<T>(1.0);
+    let q = splat::<T>(2.0) * wp1.clone() * (wp1.clone() + splat::<T>(2.0 / 3.0) * zn.clone());
+    w * (splat::<T>(1.0) + zn.clone() / wp1 * (q.clone() - zn.clone()) / (q - splat::<T>(2.0) * zn))
+}
+
+pub fn w0_simd<T: SimdRealField>(z: T) -> T
+where
+    T::Element: Lane,
+{
+    let zc = offset_from_branch_point(z.clone());
+    let p = (splat::<T>(2.0 * core::f64::consts::E) * zc).simd_sqrt();
+    let series = puiseux(p.clone()) - splat::<T>(1.0);
+
+    // The global approximation by Winitzki.
+    let l = z.clone().simd_ln_1p();
+    let winitzki = l.clone() * (splat::<T>(1.0) - l.clone().simd_ln_1p() / (splat::<T>(2.0) + l));
+
+    let mut w = series
+        .clone()
+        .select(p.clone().simd_lt(splat::<T>(SERIES_START_P)), winitzki);
+    for _ in 0..2 {
+        w = fritsch(w.clone(), (z.clone() / w).simd_ln());
+    }
+
+    let w = series.select(p.simd_le(splat::<T>(T::Element::MAX_P)), w);
+    // The iteration takes the logarithm of w, so the exact function values at 0 and infinity are set directly.
+    let exact = z.clone().simd_eq(splat::<T>(0.0)) | z.clone().simd_eq(splat::<T>(f64::INFINITY));
+    let w = z.clone().select(exact, w);
+    // This also catches NAN.
+    w.select(
+        z.simd_ge(splat::<T>(T::Element::NEG_INV_E)),
+        splat::<T>(f64::NAN),
+    )
+}
+
+pub fn wm1_simd<T: SimdRealField>(z: T) -> T
+where
+    T::Element: Lane,
+{
+    let zc = offset_from_branch_point(z.clone());
+    let p = -(splat::<T>(2.0 * core::f64::consts::E) * zc).simd_sqrt();
+    let series = puiseux(p.clone()) - splat::<T>(1.0);
+
+    // The first terms of the asymptotic expansion of the branch at 0.
+    let l1 = (-z.clone()).simd_ln();
+    let l2 = (-l1.clone()).simd_ln();
+    let asymptotic = l1.clone() - l2.clone() + l2 / l1.clone();
+
+    let mut w = series
+        .clone()
+        .select(p.clone().simd_gt(splat::<T>(-SERIES_START_P)), asymptotic);
+    // z/w = e^w is subnormal for the smallest arguments, so its logarithm is computed as a difference of logarithms.
+    for _ in 0..2 {
+        w = fritsch(w.clone(), l1.clone() - (-w).simd_ln());
+    }
+
+    let w = series.select(p.simd_ge(splat::<T>(-T::Element::MAX_P)), w);
+    // The iteration takes the logarithm of w, so the limit of the function as the argument approaches 0 from below is set directly,
+    // but only for a negative zero.
+    let negative_zero = z.clone().simd_eq(splat::<T>(0.0))
+        & splat::<T>(1.0)
+            .simd_copysign(z.clone())
+            .simd_lt(splat::<T>(0.0));
+    let w = splat::<T>(f64::NEG_INFINITY).select(negative_zero, w);
+    // This also catches NAN.
+    let in_domain = z.clone().simd_ge(splat::<T>(T::Element::NEG_INV_E))
+        & (z.simd_lt(splat::<T>(0.0)) | negative_zero);
+    w.select(in_domain, splat::<T>(f64::NAN))
+}
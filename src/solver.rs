@@ -0,0 +1,128 @@
+//! A reusable evaluator of the Lambert W function with a fixed configuration.
+
+use crate::{lambert_w0, lambert_wm1, refine, Accuracy, Branch, NEG_INV_E};
+
+/// Evaluates the Lambert W function with a configuration that is chosen once and then reused for every argument.
+///
+/// The configuration is built by chaining the methods of this type, starting from [`new`](LambertWSolver::new):
+///
+/// - the branch, given to [`new`](LambertWSolver::new),
+/// - the accuracy of the approximation, set with [`accuracy`](LambertWSolver::accuracy),
+/// - how many iterations of the method by Fritsch, Shafer and Crowley refine the approximation, and when they stop,
+///   set with [`max_iterations`](LambertWSolver::max_iterations) and [`tolerance`](LambertWSolver::tolerance),
+/// - how far below -1/e arguments are treated as -1/e, set with [`branch_point_tolerance`](LambertWSolver::branch_point_tolerance).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use lambert_w::{lambert_w0, Accuracy, Branch, LambertWSolver};
+///
+/// let solver = LambertWSolver::new(Branch::W0)
+///     .accuracy(Accuracy::Bits24)
+///     .max_iterations(2)
+///     .tolerance(1e-15);
+///
+/// for z in [0.1, 1.0, 10.0, 1e100] {
+///     assert_relative_eq!(solver.eval(z), lambert_w0(z), max_relative = 1e-15);
+/// }
+/// ```
+///
+/// Arguments slightly below -1/e, e.g. from rounding errors in their computation, can be treated as -1/e:
+///
+/// ```
+/// use lambert_w::{Branch, LambertWSolver, NEG_INV_E};
+///
+/// // The next float below -1/e.
+/// let z = f64::from_bits(NEG_INV_E.to_bits() + 1);
+///
+/// assert!(LambertWSolver::new(Branch::Wm1).eval(z).is_nan());
+/// assert_eq!(LambertWSolver::new(Branch::Wm1).branch_point_tolerance(4).eval(z), -1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LambertWSolver {
+    branch: Branch,
+    accuracy: Accuracy,
+    tolerance: f64,
+    max_iterations: u32,
+    branch_point_tolerance: u64,
+}
+
+impl LambertWSolver {
+    /// Creates a solver for the given branch that evaluates the approximation with 50 bits of accuracy
+    /// without refining it, and gives [`NAN`](f64::NAN) for all arguments below -1/e.
+    pub const fn new(branch: Branch) -> Self {
+        Self {
+            branch,
+            accuracy: Accuracy::Bits50,
+            tolerance: f64::EPSILON,
+            max_iterations: 0,
+            branch_point_tolerance: 0,
+        }
+    }
+
+    /// Sets the accuracy of the approximation that the solver starts from.
+    pub const fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Sets the largest number of iterations by Fritsch, Shafer and Crowley that refine the approximation.
+    /// Each iteration has quartic convergence, so one iteration refines the approximation with 24 bits of accuracy
+    /// to about the precision of `f64`. The default is 0.
+    pub const fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Sets the relative change of an iteration below which the refinement stops early. The default is [`f64::EPSILON`].
+    pub const fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets how many representable numbers below -1/e are treated as -1/e, where both branches are -1.
+    /// Arguments further below -1/e result in [`NAN`](f64::NAN). The default is 0.
+    pub const fn branch_point_tolerance(mut self, ulps: u64) -> Self {
+        self.branch_point_tolerance = ulps;
+        self
+    }
+
+    /// Evaluates the branch of the solver at `z` with its configuration.
+    pub fn eval(&self, z: f64) -> f64 {
+        // Both numbers are negative, so the larger bit pattern belongs to the number with the larger magnitude.
+        // This also gives the exact value at -1/e, which the approximation with 24 bits of accuracy does not.
+        if z <= NEG_INV_E && z.to_bits() - NEG_INV_E.to_bits() <= self.branch_point_tolerance {
+            return -1.0;
+        }
+
+        let mut w = self.approximate(z);
+        // The iteration is undefined at 0 and -1, where the approximations are exact, and at infinity.
+        if w == 0.0 || w == -1.0 || !w.is_finite() {
+            return w;
+        }
+        for _ in 0..self.max_iterations {
+            let next = refine::fritsch(z, w);
+            let change = (next - w).abs();
+            w = next;
+            if change <= self.tolerance * w.abs() {
+                break;
+            }
+        }
+        w
+    }
+
+    /// Evaluates the approximation of the branch at `z` with the accuracy of the solver.
+    fn approximate(&self, z: f64) -> f64 {
+        match (self.branch, self.accuracy) {
+            (Branch::W0, Accuracy::Bits24) => crate::sp_lambert_w0(z),
+            (Branch::Wm1, Accuracy::Bits24) => crate::sp_lambert_wm1(z),
+            (Branch::W0, Accuracy::Bits50) => lambert_w0(z),
+            (Branch::Wm1, Accuracy::Bits50) => lambert_wm1(z),
+            (Branch::W0, Accuracy::Full) => refine::fritsch_checked(z, lambert_w0(z)),
+            (Branch::Wm1, Accuracy::Full) => refine::fritsch_checked(z, lambert_wm1(z)),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! The checks of the arguments in builds with `--cfg lambert_w_strict_debug`.
+
+use core::fmt::Display;
+
+/// Panics if `in_domain` is `false` and debug assertions are enabled.
+///
+/// `in_domain` should be `false` if the function returned NaN for an argument `z` that was not NaN,
+/// so that the first invalid argument in a computation is found where it is given to a function of this crate,
+/// and not where the NaN that it resulted in is noticed.
+#[track_caller]
+#[inline(always)]
+pub fn debug_assert_in_domain(function: &str, z: impl Display, in_domain: bool) {
+    debug_assert!(
+        in_domain,
+        "`{function}` was called with the argument {z}, which is outside its domain"
+    );
+}
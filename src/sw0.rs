@@ -1,5 +1,5 @@
-use super::NEG_INV_E;
 use crate::{
+    consts::NEG_INV_E,
     elementary::{ln, sqrt},
     rational::{rational_3_over_3, rational_4_over_3},
 };
@@ -10,6 +10,14 @@ use crate::{
 // It returns f64::NAN if the input is negative or NAN,
 // and f64::INFINITY if the input is positive infinity.
 
+// The correctly rounded values of the function at the seams between the subdomains
+// where the approximations on either side of the seam would otherwise make the function decrease.
+// The approximations are clamped to these values, which only changes them close to the seam,
+// and there only by less than their error.
+const W_X1_X2: f64 = 0.854_493_753_927_381_2;
+const W_X17_U18: f64 = 43.686_518_640_445_53;
+const W_U18_U19: f64 = 319.673_018_497_742_53;
+
 pub fn sw0(z: f64) -> f64 {
     if z < NEG_INV_E || z.is_nan() {
         f64::NAN
@@ -32,6 +40,7 @@ pub fn sw0(z: f64) -> f64 {
                 0.186_158_234_528_316_23,
             ],
         )
+        .min(W_X1_X2)
     } else if z <= 30.539_142_109_510_895 {
         // W <= 2.502, X_2
 
@@ -50,6 +59,7 @@ pub fn sw0(z: f64) -> f64 {
                 0.006_881_368_648_675_912,
             ],
         )
+        .max(W_X1_X2)
     } else if z <= 371.669_843_713_757_76 {
         // W <= 4.430, X_3
 
@@ -320,6 +330,7 @@ pub fn sw0(z: f64) -> f64 {
                 1.419_524_481_080_098_4e-30,
             ],
         )
+        .min(W_X17_U18)
     } else if z <= 2.172_370_661_049_060_6e141 {
         // W <= 319.673, U_18
 
@@ -338,6 +349,7 @@ pub fn sw0(z: f64) -> f64 {
                 -4.243_840_393_198_107e-10,
             ],
         )
+        .clamp(W_X17_U18, W_U18_U19)
     } else if z < f64::INFINITY {
         //    U_19
 
@@ -356,6 +368,7 @@ pub fn sw0(z: f64) -> f64 {
                 -3.748_153_583_315_12e-14,
             ],
         )
+        .max(W_U18_U19)
     } else {
         f64::INFINITY
     }
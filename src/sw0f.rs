@@ -1,10 +1,9 @@
 use crate::{
+    consts::NEG_INV_E_F32 as NEG_INV_E,
     elementary::{lnf, sqrtf},
     rational::{rational_3_over_3f, rational_4_over_3f},
 };
 
-const NEG_INV_E: f32 = super::NEG_INV_E as f32;
-
 // This is an implementation of the approximation of the principal
 // branch of the Lambert W function
 // with 24 bits of accuracy from Fukushima's paper.
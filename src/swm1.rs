@@ -1,5 +1,5 @@
-use super::{INV_SQRT_E, NEG_INV_E};
 use crate::{
+    consts::{INV_SQRT_E, NEG_INV_E},
     elementary::{ln, sqrt},
     rational::rational_3_over_3,
 };
@@ -7,7 +7,8 @@ use crate::{
 // This is an implementation of the approximation of the secondary
 // branch of the Lambert W function
 // with 24 bits of accuracy from Fukushima's paper.
-// It returns f64::NAN if the input is smaller than -1/e, is NAN, or is larger than or equal to 0.
+// It returns f64::NAN if the input is smaller than -1/e, is NAN, or is larger than or equal to 0,
+// except for -0.0 where it returns f64::NEG_INFINITY.
 
 pub fn swm1(z: f64) -> f64 {
     if z < NEG_INV_E {
@@ -192,6 +193,9 @@ pub fn swm1(z: f64) -> f64 {
                 -3.922_277_308_457_406_3e-14,
             ],
         )
+    } else if z == 0.0 && z.is_sign_negative() {
+        // The limit of the function as the argument approaches 0 from below.
+        f64::NEG_INFINITY
     } else {
         f64::NAN
     }
@@ -1,15 +1,14 @@
 use crate::{
+    consts::{INV_SQRT_E_F32 as INV_SQRT_E, NEG_INV_E_F32 as NEG_INV_E},
     elementary::{lnf, sqrtf},
     rational::rational_3_over_3f,
 };
 
-const INV_SQRT_E: f32 = super::INV_SQRT_E as f32;
-const NEG_INV_E: f32 = super::NEG_INV_E as f32;
-
 // This is an implementation of the approximation of the secondary
 // branch of the Lambert W function
 // with 24 bits of accuracy from Fukushima's paper.
-// It returns f32::NAN if the input is smaller than -1/e, is NAN, or is larger than or equal to 0.
+// It returns f32::NAN if the input is smaller than -1/e, is NAN, or is larger than or equal to 0,
+// except for -0.0 where it returns f32::NEG_INFINITY.
 
 pub fn swm1f(z: f32) -> f32 {
     if z < NEG_INV_E {
@@ -86,6 +85,9 @@ pub fn swm1f(z: f32) -> f32 {
             [-1.441_124_7, 1.281_927, -0.074_979_36, 0.000_476_363_1],
             [1.0, -0.072_000_876, 0.000_475_489_33, -4.171_498e-10],
         )
+    } else if z == 0.0 && z.is_sign_negative() {
+        // The limit of the function as the argument approaches 0 from below.
+        f32::NEG_INFINITY
     } else {
         f32::NAN
     }
@@ -0,0 +1,115 @@
+//! A table of the principal branch on an interval, evaluated with cubic Hermite interpolation.
+
+use core::ops::RangeInclusive;
+use std::vec::Vec;
+
+use crate::{lambert_w0, NEG_INV_E};
+
+/// A precomputed table of the principal branch of the Lambert W function on a closed interval,
+/// evaluated with cubic Hermite interpolation between equally spaced points.
+///
+/// The table stores the function value and the derivative at every point, both computed with [`lambert_w0`].
+/// Evaluating it takes a few arithmetic operations and no branches that depend on the argument,
+/// which trades memory and accuracy for a short and predictable evaluation time.
+/// The error decreases with the fourth power of the spacing between the points, but is largest close to -1/e,
+/// where the function has a square root singularity.
+///
+/// Only available with the `std` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use approx::assert_abs_diff_eq;
+/// use lambert_w::{lambert_w0, W0Table};
+///
+/// let table = W0Table::new(0.0..=10.0, 1000).unwrap();
+///
+/// assert_abs_diff_eq!(table.eval(3.3), lambert_w0(3.3), epsilon = 1e-8);
+/// ```
+///
+/// Arguments outside the interval of the table are clamped to it:
+///
+/// ```
+/// # use lambert_w::W0Table;
+/// let table = W0Table::new(0.0..=10.0, 1000).unwrap();
+///
+/// assert_eq!(table.eval(20.0), table.eval(10.0));
+/// assert!(table.eval(f64::NAN).is_nan());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct W0Table {
+    lo: f64,
+    hi: f64,
+    inv_spacing: f64,
+    /// The function value and the derivative times the spacing at every point.
+    points: Vec<[f64; 2]>,
+}
+
+impl W0Table {
+    /// Creates a table of the principal branch with `n_points` equally spaced points on `range`.
+    ///
+    /// Returns `None` if `n_points` is less than 2, or if the range is empty, not finite, or extends to -1/e or below,
+    /// since the derivative of the function is infinite at -1/e.
+    pub fn new(range: RangeInclusive<f64>, n_points: usize) -> Option<Self> {
+        let (lo, hi) = range.into_inner();
+        if !(lo > NEG_INV_E && lo < hi && hi.is_finite() && n_points >= 2) {
+            return None;
+        }
+
+        let intervals = (n_points - 1) as f64;
+        let spacing = (hi - lo) / intervals;
+        let points = (0..n_points)
+            .map(|i| {
+                let z = if i == n_points - 1 {
+                    hi
+                } else {
+                    lo + spacing * i as f64
+                };
+                let w = lambert_w0(z);
+                // W'(z) = W(z) / (z * (1 + W(z))), which is 1 at z = 0.
+                let derivative = if z == 0.0 { 1.0 } else { w / (z * (1.0 + w)) };
+                [w, derivative * spacing]
+            })
+            .collect();
+
+        Some(Self {
+            lo,
+            hi,
+            inv_spacing: intervals / (hi - lo),
+            points,
+        })
+    }
+
+    /// Evaluates the table at `z`.
+    ///
+    /// Arguments outside the interval of the table are clamped to it, and [`NAN`](f64::NAN) results in [`NAN`](f64::NAN).
+    pub fn eval(&self, z: f64) -> f64 {
+        let t = (z.clamp(self.lo, self.hi) - self.lo) * self.inv_spacing;
+        // Converting NAN to an integer gives 0, and the NAN then propagates through the interpolation.
+        let i = (t as usize).min(self.points.len() - 2);
+        let u = t - i as f64;
+        let [w0, d0] = self.points[i];
+        let [w1, d1] = self.points[i + 1];
+
+        // The cubic Hermite basis polynomials.
+        let u2 = u * u;
+        let u3 = u2 * u;
+        let h00 = 2.0 * u3 - 3.0 * u2 + 1.0;
+        let h10 = u3 - 2.0 * u2 + u;
+        let h01 = -2.0 * u3 + 3.0 * u2;
+        let h11 = u3 - u2;
+        h00 * w0 + h10 * d0 + h01 * w1 + h11 * d1
+    }
+
+    /// Returns the interval that the table covers.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.lo..=self.hi
+    }
+
+    /// Returns the number of points in the table.
+    pub fn n_points(&self) -> usize {
+        self.points.len()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    elementary::{exp, sqrt},
+    lambert_w0,
+};
+
+// This file contains propagation of the uncertainty of an argument through the principal branch
+// of the Lambert W function with Taylor expansions of the function around the mean of the argument.
+// The derivatives are computed from the function value w:
+// W0'(z) = e^(-w)/(1 + w) and W0''(z) = -e^(-2w)·(2 + w)/(1 + w)^3.
+// They are infinite at the branch point, where w = -1.
+
+/// Returns the value of the function at `value` together with its first and second derivatives there.
+fn w0_with_derivatives(value: f64) -> (f64, f64, f64) {
+    let w = lambert_w0(value);
+    let wp1 = w + 1.0;
+    let e = exp(-w);
+    let first = e / wp1;
+    let second = -first * e * (w + 2.0) / (wp1 * wp1);
+    (w, first, second)
+}
+
+pub fn w0_uncertain(value: f64, sigma: f64) -> (f64, f64) {
+    let (w, first, _) = w0_with_derivatives(value);
+    if sigma < 0.0 || sigma.is_nan() || w.is_nan() {
+        (f64::NAN, f64::NAN)
+    } else if sigma == 0.0 {
+        // An exact argument has an exact function value, even at the branch point.
+        (w, 0.0)
+    } else {
+        (w, first * sigma)
+    }
+}
+
+pub fn w0_uncertain_second_order(value: f64, sigma: f64) -> (f64, f64) {
+    let (w, first, second) = w0_with_derivatives(value);
+    if sigma < 0.0 || sigma.is_nan() || w.is_nan() {
+        (f64::NAN, f64::NAN)
+    } else if sigma == 0.0 {
+        (w, 0.0)
+    } else {
+        let variance = sigma * sigma;
+        // For a normally distributed argument the second order terms shift the mean by W''·σ²/2
+        // and add (W''·σ²)²/2 to the variance.
+        let shift = 0.5 * second * variance;
+        (
+            w + shift,
+            sqrt(first * first * variance + 2.0 * shift * shift),
+        )
+    }
+}
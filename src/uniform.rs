@@ -0,0 +1,19 @@
+use crate::{elementary::ln_1p, refine::fritsch};
+
+// This is an implementation of a branch-free approximation of the principal
+// branch of the Lambert W function for non-negative inputs.
+// It starts from the global approximation by Winitzki and performs
+// two steps of the iteration by Fritsch, Shafer and Crowley.
+// It returns f64::NAN if the input is negative or NAN,
+// and returns the input unchanged if it is 0 or f64::INFINITY.
+
+pub fn w0_uniform(z: f64) -> f64 {
+    if !(z > 0.0 && z < f64::INFINITY) {
+        return if z >= 0.0 { z } else { f64::NAN };
+    }
+
+    let l = ln_1p(z);
+    let w = l * (1.0 - ln_1p(l) / (2.0 + l));
+
+    fritsch(z, fritsch(z, w))
+}
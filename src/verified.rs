@@ -0,0 +1,139 @@
+use crate::{elementary::exp, lambert_w0, lambert_wm1};
+
+// This file contains evaluations of the Lambert W function whose results are certified
+// by an enclosure of the exact function value. The enclosure is checked by evaluating
+// w·e^w - z at its endpoints together with bounds on the rounding errors of the evaluation:
+// if the residual provably has different signs at the two endpoints, the function value lies
+// between them, since w·e^w is continuous and strictly monotonic on each branch.
+// If the check fails the enclosure is widened and the check repeated.
+
+/// Bounds the relative error of the computed value of w·e^w. This assumes that the exponential
+/// has a relative error of at most 2 ulps, and leaves room for the roundings of the product and the bounds.
+const PRODUCT_REL_ERROR: f64 = 4.0 * f64::EPSILON;
+
+/// Bounds the absolute error of the computed value of w·e^w when the exponential is subnormal.
+const PRODUCT_ABS_ERROR: f64 = 1024.0 * f64::MIN_POSITIVE * f64::EPSILON;
+
+/// The half-width of the first enclosure that is tried, relative to the magnitude of the function value.
+const INITIAL_HALF_WIDTH: f64 = 4.0 * f64::EPSILON;
+
+/// The factor that the half-width of the enclosure is multiplied by each time the check fails.
+const WIDENING_FACTOR: f64 = 8.0;
+
+/// A value of the Lambert W function together with an enclosure of the exact function value.
+///
+/// Created by [`lambert_w0_verified`](crate::lambert_w0_verified) or [`lambert_wm1_verified`](crate::lambert_wm1_verified).
+///
+/// # Examples
+///
+/// ```
+/// use lambert_w::{lambert_w0_verified, OMEGA};
+///
+/// let w = lambert_w0_verified(1.0);
+///
+/// assert!(w.is_certified());
+/// assert_eq!(w.value(), OMEGA);
+///
+/// let (lo, hi) = w.enclosure();
+/// assert!(lo < OMEGA && OMEGA < hi);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Verified {
+    value: f64,
+    lo: f64,
+    hi: f64,
+    certified: bool,
+}
+
+impl Verified {
+    /// Returns the value of the function. This is the same value as the corresponding
+    /// function without certification returns.
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    /// Returns the lower and upper ends of an interval that contains the exact function value.
+    ///
+    /// If the result could not be certified this is the entire range of the branch.
+    /// If the argument is outside the domain of the branch both ends are [`NAN`](f64::NAN).
+    pub const fn enclosure(self) -> (f64, f64) {
+        (self.lo, self.hi)
+    }
+
+    /// Returns `true` if the enclosure was certified to contain the exact function value
+    /// by a check of the residual w·e^w - z at its ends.
+    pub const fn is_certified(self) -> bool {
+        self.certified
+    }
+}
+
+/// Returns a lower and an upper bound on the exact value of x·e^x.
+fn product_bounds(x: f64) -> (f64, f64) {
+    let p = x * exp(x);
+    let margin = PRODUCT_REL_ERROR * p.abs() + PRODUCT_ABS_ERROR;
+    (p - margin, p + margin)
+}
+
+pub fn w0_verified(z: f64) -> Verified {
+    let w = lambert_w0(z);
+    // The residual is increasing on the principal branch, where w >= -1.
+    verify(z, w, -1.0, f64::INFINITY, |lo, hi| {
+        product_bounds(lo).1 <= z && product_bounds(hi).0 >= z
+    })
+}
+
+pub fn wm1_verified(z: f64) -> Verified {
+    let w = lambert_wm1(z);
+    // The residual is decreasing on the secondary branch, where w <= -1.
+    verify(z, w, f64::NEG_INFINITY, -1.0, |lo, hi| {
+        product_bounds(lo).0 >= z && product_bounds(hi).1 <= z
+    })
+}
+
+/// Searches for an enclosure around `w` within the range of the branch from `min` to `max`
+/// for which `encloses` returns `true`.
+fn verify(z: f64, w: f64, min: f64, max: f64, encloses: impl Fn(f64, f64) -> bool) -> Verified {
+    if w.is_nan() {
+        return Verified {
+            value: w,
+            lo: f64::NAN,
+            hi: f64::NAN,
+            certified: false,
+        };
+    }
+
+    // The function values at 0 and the infinite function values at the ends of the domain are exact.
+    if z == 0.0 || w.is_infinite() {
+        return Verified {
+            value: w,
+            lo: w,
+            hi: w,
+            certified: true,
+        };
+    }
+
+    // The approximations only have 50 bits of absolute accuracy close to w = 0, so the enclosure is widened
+    // until it is as wide as the larger of the magnitude of the function value and 1.
+    let max_half_width = w.abs().max(1.0);
+    let mut half_width = (INITIAL_HALF_WIDTH * w.abs()).max(f64::from_bits(1));
+    while half_width <= max_half_width {
+        let lo = (w - half_width).max(min);
+        let hi = (w + half_width).min(max);
+        if encloses(lo, hi) {
+            return Verified {
+                value: w,
+                lo,
+                hi,
+                certified: true,
+            };
+        }
+        half_width *= WIDENING_FACTOR;
+    }
+
+    Verified {
+        value: w,
+        lo: min,
+        hi: max,
+        certified: false,
+    }
+}
@@ -4,8 +4,23 @@
 //! and then switches to [`assert_relative_eq!`] when the first assertion would fail.
 
 use lambert_w::{
-    lambert_w0, lambert_w0f, lambert_wm1, lambert_wm1f, sp_lambert_w0, sp_lambert_wm1, LambertW,
-    NEG_INV_E, OMEGA,
+    fritsch_refine, halley_refine, lambert_w, lambert_w0, lambert_w0_approx, lambert_w0_clamped,
+    lambert_w0_from_offset, lambert_w0_large, lambert_w0_linspace, lambert_w0_near_branch,
+    lambert_w0_neg_exp, lambert_w0_roundtrip, lambert_w0_segment, lambert_w0_small,
+    lambert_w0_smooth, lambert_w0_uncertain, lambert_w0_uncertain_second_order, lambert_w0_uniform,
+    lambert_w0_verified, lambert_w0_with, lambert_w0_with_accuracy, lambert_w0_with_domain_policy,
+    lambert_w0_with_error_estimate, lambert_w0_with_kernel, lambert_w0f, lambert_w0f_precise,
+    lambert_wm1, lambert_wm1_from_offset, lambert_wm1_near_branch, lambert_wm1_segment,
+    lambert_wm1_smooth, lambert_wm1_verified, lambert_wm1_with, lambert_wm1_with_accuracy,
+    lambert_wm1_with_domain_policy, lambert_wm1_with_kernel, lambert_wm1f, lambert_wm1f_precise,
+    sp_lambert_w0, sp_lambert_wm1, tetration_limit, Accuracy, Accuracy24, Accuracy50, AccuracyTier,
+    Branch, BranchIndexError, ClampPolicy, DefaultKernel, DomainError, ErrorPolicy, LambertW,
+    LambertWSolver, MathKernel, NanPolicy, SegmentId, NEG_INV_E, OMEGA,
+};
+
+use lambert_w::{
+    consts,
+    equations::{solve_exp_linear, solve_x_pow, RealSolutions},
 };
 
 use approx::{assert_abs_diff_eq, assert_relative_eq};
@@ -17,7 +32,7 @@ fn test_lambert_w0() {
     assert_abs_diff_eq!(lambert_w0(NEG_INV_E), -1.0);
     assert_abs_diff_eq!(
         lambert_w0(NEG_INV_E + f64::EPSILON),
-        -0.999_999_965_255_7976
+        -0.999_999_966_242_187_7
     );
     assert_abs_diff_eq!(
         lambert_w0(-2.678_794_411_714_424e-1),
@@ -72,6 +87,36 @@ fn test_lambert_w0() {
     assert_eq!(lambert_w0(f64::INFINITY), f64::INFINITY);
 }
 
+#[test]
+fn test_lambert_w0_close_to_zero() {
+    // The arguments with the function values rounded from arbitrary precision evaluations.
+    for (z, exact) in [
+        (1e-300, 1e-300),
+        (-1e-20, -1e-20),
+        (1e-10, 9.999_999_999e-11),
+        (-1e-10, -1.000_000_000_100_000_1e-10),
+        (0.001, 0.000_999_001_497_338_530_8),
+        (-0.1, -0.111_832_559_158_962_97),
+    ] {
+        assert_relative_eq!(lambert_w0(z), exact, max_relative = 4.0 * f64::EPSILON);
+    }
+
+    // The approximation only has absolute accuracy between the arguments where the Taylor series is used and 0.125,
+    // but the refinement of `Accuracy::Full` recovers the relative accuracy.
+    for (z, exact) in [
+        (1e-7, 9.999_999_000_000_15e-8),
+        (-1e-6, -1.000_001_000_001_499_9e-6),
+        (0.001, 0.000_999_001_497_338_530_8),
+        (-0.1, -0.111_832_559_158_962_97),
+    ] {
+        assert_relative_eq!(
+            lambert_w0_with_accuracy(z, Accuracy::Full),
+            exact,
+            max_relative = 4.0 * f64::EPSILON
+        );
+    }
+}
+
 #[test]
 fn test_sp_lambert_w0() {
     assert!(sp_lambert_w0(NEG_INV_E - f64::EPSILON).is_nan());
@@ -254,7 +299,7 @@ fn test_lambert_wm1() {
     assert_abs_diff_eq!(lambert_wm1(NEG_INV_E), -1.0);
     assert_relative_eq!(
         lambert_wm1(-3.578_794_411_714_423e-1),
-        -1.253_493_791_367_214,
+        -1.253_493_791_367_214_5,
         max_relative = 4e-16
     );
     assert_relative_eq!(
@@ -313,7 +358,7 @@ fn test_sp_lambert_wm1() {
     assert_relative_eq!(sp_lambert_wm1(NEG_INV_E), -1.0, max_relative = 1e-7);
     assert_relative_eq!(
         sp_lambert_wm1(-3.578_794_411_714_423e-1),
-        -1.253_493_791_367_214,
+        -1.253_493_791_367_214_5,
         max_relative = 1e-7
     );
     assert_relative_eq!(
@@ -401,6 +446,18 @@ fn test_lambert_wm1f() {
     assert_relative_eq!(lambert_wm1f(-3e-5), -1.297_753_2e1);
     assert_relative_eq!(lambert_wm1f(-1e-5), -1.416_360_1e1);
     assert_relative_eq!(lambert_wm1f(-1e-20), -49.962_986);
+    // Subnormal arguments keep their relative accuracy.
+    assert_relative_eq!(
+        lambert_wm1f(-f32::MIN_POSITIVE),
+        -91.856_78,
+        max_relative = 4e-7
+    );
+    assert_relative_eq!(lambert_wm1f(-1e-40), -96.674_76, max_relative = 4e-7);
+    assert_relative_eq!(
+        lambert_wm1f(-f32::from_bits(1)),
+        -107.960_7,
+        max_relative = 4e-7
+    );
     assert!(lambert_wm1f(f32::EPSILON).is_nan());
     assert!(lambert_wm1f(f32::INFINITY).is_nan());
 }
@@ -413,7 +470,7 @@ fn test_trait_impl_on_f64() {
     );
     assert_relative_eq!(
         (-3.578_794_411_714_423e-1_f64).lambert_wm1(),
-        -1.253493791367214,
+        -1.253_493_791_367_214_5,
         max_relative = 4e-16
     );
 }
@@ -427,3 +484,1910 @@ fn test_trait_impl_on_f32() {
         max_relative = 1e-6
     );
 }
+
+#[test]
+fn test_trait_impl_on_integers() {
+    assert_eq!(2_i32.lambert_w0(), lambert_w0(2.0));
+    assert_eq!(u32::MAX.lambert_w0(), lambert_w0(4_294_967_295.0));
+    assert_eq!(i64::MAX.lambert_w0(), lambert_w0(9.223_372_036_854_776e18));
+    assert_eq!(
+        u64::MAX.lambert_w0(),
+        lambert_w0(1.844_674_407_370_955_2e19)
+    );
+    assert_eq!(0_u64.lambert_w0(), 0.0);
+    assert!((-1_i32).lambert_w0().is_nan());
+    assert!(i64::MIN.lambert_w0().is_nan());
+    for wm1 in [
+        (-1_i32).lambert_wm1(),
+        0_u32.lambert_wm1(),
+        0_i64.lambert_wm1(),
+        1_u64.lambert_wm1(),
+    ] {
+        assert!(wm1.is_nan());
+    }
+}
+
+#[test]
+fn test_tetration_limit() {
+    assert!(tetration_limit(f64::NAN).is_nan());
+    assert!(tetration_limit(0.065).is_nan());
+    assert!(tetration_limit(1.445).is_nan());
+    assert!(tetration_limit(-1.0).is_nan());
+    assert_abs_diff_eq!(
+        tetration_limit(0.065_988_035_845_312_54),
+        0.367_879_441_171_442_3,
+        epsilon = 1e-15
+    );
+    assert_abs_diff_eq!(tetration_limit(0.1), 0.399_012_978_260_252_1);
+    assert_abs_diff_eq!(tetration_limit(0.5), 0.641_185_744_504_986);
+    assert_eq!(tetration_limit(1.0), 1.0);
+    assert_abs_diff_eq!(tetration_limit(1.0 - 1e-10), 0.999_999_999_9);
+    assert_abs_diff_eq!(tetration_limit(1.2), 1.257_734_541_376_526_4);
+    assert_abs_diff_eq!(tetration_limit(f64::sqrt(2.0)), 2.0, epsilon = 1e-15);
+    assert_abs_diff_eq!(
+        tetration_limit(1.444_667_861_009_766),
+        core::f64::consts::E,
+        epsilon = 1e-7
+    );
+}
+
+#[test]
+fn test_solve_x_pow() {
+    assert_eq!(solve_x_pow(f64::NAN, 2.0), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(1.0, f64::NAN), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(1.0, f64::INFINITY), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(1.0, 0.0), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(1.0, -1.0), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(-1.0, 2.0), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(1.0, 0.5), RealSolutions::Zero);
+    assert_eq!(solve_x_pow(3.5, 1.0), RealSolutions::One(3.5));
+    assert_eq!(solve_x_pow(0.0, 2.0), RealSolutions::One(0.0));
+    assert_eq!(solve_x_pow(-1.0, 2.0).count(), Some(0));
+
+    match solve_x_pow(8.0, 2.0) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, 2.0),
+        other => panic!("expected one solution, got {other:?}"),
+    }
+    match solve_x_pow(-2.0, 0.5) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, -1.0, epsilon = 1e-15),
+        other => panic!("expected one solution, got {other:?}"),
+    }
+    match solve_x_pow(0.5, 0.5) {
+        RealSolutions::Two(x1, x2) => {
+            assert_abs_diff_eq!(x1, 1.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(x2, 2.0, epsilon = 1e-14);
+        }
+        other => panic!("expected two solutions, got {other:?}"),
+    }
+    match solve_x_pow(-0.5, 2.0) {
+        RealSolutions::Two(x1, x2) => {
+            assert_abs_diff_eq!(x1, -2.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(x2, -1.0, epsilon = 1e-14);
+        }
+        other => panic!("expected two solutions, got {other:?}"),
+    }
+    assert_eq!(solve_x_pow(-0.5, 2.0).count(), Some(2));
+    match solve_x_pow(NEG_INV_E, core::f64::consts::E) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, -1.0),
+        other => panic!("expected one solution, got {other:?}"),
+    }
+
+    // c·ln(b) overflows. The solution satisfies ln(x) + x·ln(b) = ln(c).
+    let b = 10.0_f64.exp();
+    for c in [1e300, 1e308] {
+        match solve_x_pow(c, b) {
+            RealSolutions::One(x) => {
+                assert_relative_eq!(x.ln() + b.ln() * x, c.ln(), max_relative = 1e-15)
+            }
+            other => panic!("expected one solution, got {other:?}"),
+        }
+    }
+    assert_eq!(solve_x_pow(-1e308, b), RealSolutions::Zero);
+    // c·ln(b) underflows to a negative subnormal number.
+    let b = 1e-10_f64.exp();
+    match solve_x_pow(-1e-300, b) {
+        RealSolutions::Two(x1, x2) => {
+            assert_relative_eq!(
+                (-x1).ln() + b.ln() * x1,
+                1e-300_f64.ln(),
+                max_relative = 1e-15
+            );
+            assert_eq!(x2, -1e-300);
+        }
+        other => panic!("expected two solutions, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_solve_exp_linear() {
+    assert_eq!(
+        solve_exp_linear(f64::NAN, 1.0, 1.0, 1.0),
+        RealSolutions::Zero
+    );
+    assert_eq!(
+        solve_exp_linear(1.0, 1.0, f64::INFINITY, 1.0),
+        RealSolutions::Zero
+    );
+
+    // a = 0 or b = 0 gives a linear equation.
+    assert_eq!(
+        solve_exp_linear(0.0, 1.0, 2.0, -4.0),
+        RealSolutions::One(2.0)
+    );
+    assert_eq!(solve_exp_linear(0.0, 1.0, 0.0, 1.0), RealSolutions::Zero);
+    assert_eq!(
+        solve_exp_linear(0.0, 1.0, 0.0, 0.0),
+        RealSolutions::Infinite
+    );
+    assert_eq!(
+        solve_exp_linear(5.0, 0.0, 2.0, 1.0),
+        RealSolutions::One(2.0)
+    );
+    assert_eq!(solve_exp_linear(5.0, 0.0, 0.0, 1.0), RealSolutions::Zero);
+    assert_eq!(
+        solve_exp_linear(5.0, 0.0, 0.0, 5.0),
+        RealSolutions::Infinite
+    );
+    assert_eq!(solve_exp_linear(5.0, 0.0, 0.0, 5.0).count(), None);
+
+    // c = 0 gives a·e^(b·x) = d.
+    match solve_exp_linear(2.0, 0.5, 0.0, 2.0 * core::f64::consts::E) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, 2.0),
+        other => panic!("expected one solution, got {other:?}"),
+    }
+    assert_eq!(solve_exp_linear(2.0, 0.5, 0.0, -1.0), RealSolutions::Zero);
+    assert_eq!(solve_exp_linear(2.0, 0.5, 0.0, 0.0), RealSolutions::Zero);
+
+    // e^x = x has no real solutions.
+    assert_eq!(solve_exp_linear(1.0, 1.0, 1.0, 0.0), RealSolutions::Zero);
+
+    // -e^x = x - d, or x + e^x = d, where e^d overflows when d is larger than about 709.78.
+    for d in [700.0, 709.0, 710.0, 800.0, 1e5] {
+        match solve_exp_linear(-1.0, 1.0, 1.0, -d) {
+            RealSolutions::One(x) => assert_relative_eq!(x + x.exp(), d, max_relative = 1e-13),
+            other => panic!("expected one solution, got {other:?}"),
+        }
+    }
+    // 1e300·e^x = x + 800, where e^(-800) underflows.
+    match solve_exp_linear(1e300, 1.0, 1.0, 800.0) {
+        RealSolutions::Two(x1, x2) => {
+            assert_eq!(x1, -800.0);
+            assert_relative_eq!(1e300_f64.ln() + x2, (x2 + 800.0).ln(), max_relative = 1e-13);
+        }
+        other => panic!("expected two solutions, got {other:?}"),
+    }
+    // e^x = x - 800 has no real solutions even though e^800 overflows.
+    assert_eq!(
+        solve_exp_linear(-1.0, 1.0, -1.0, 800.0),
+        RealSolutions::Zero
+    );
+    // e^x = e·x touches the line at x = 1.
+    match solve_exp_linear(1.0, 1.0, core::f64::consts::E, 0.0) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, 1.0, epsilon = 1e-7),
+        RealSolutions::Two(x1, x2) => {
+            assert_abs_diff_eq!(x1, 1.0, epsilon = 1e-7);
+            assert_abs_diff_eq!(x2, 1.0, epsilon = 1e-7);
+        }
+        other => panic!("expected a double solution, got {other:?}"),
+    }
+    match solve_exp_linear(1.0, 1.0, -1.0, 0.0) {
+        RealSolutions::One(x) => assert_abs_diff_eq!(x, -OMEGA),
+        other => panic!("expected one solution, got {other:?}"),
+    }
+    match solve_exp_linear(1.0, core::f64::consts::LN_2, 2.0, 0.0) {
+        RealSolutions::Two(x1, x2) => {
+            assert_abs_diff_eq!(x1, 1.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(x2, 2.0, epsilon = 1e-14);
+        }
+        other => panic!("expected two solutions, got {other:?}"),
+    }
+    // 3·e^(-x) = x + 1 has a solution with W0 only.
+    match solve_exp_linear(3.0, -1.0, 1.0, 1.0) {
+        RealSolutions::One(x) => {
+            assert_abs_diff_eq!(3.0 * f64::exp(-x), x + 1.0, epsilon = 1e-14)
+        }
+        other => panic!("expected one solution, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_f32_consts() {
+    assert_eq!(consts::NEG_INV_E_F32, NEG_INV_E as f32);
+    assert_eq!(consts::OMEGA_F32, OMEGA as f32);
+    assert_eq!(consts::INV_SQRT_E_F32, consts::INV_SQRT_E as f32);
+    assert_abs_diff_eq!(lambert_w0f(consts::NEG_INV_E_F32), -1.0);
+    assert!(lambert_w0f(consts::NEG_INV_E_F32 - f32::EPSILON).is_nan());
+    assert_abs_diff_eq!(lambert_w0f(1.0), consts::OMEGA_F32);
+}
+
+#[test]
+fn test_exact_special_values() {
+    assert_eq!(lambert_w0(0.0), 0.0);
+    assert_eq!(lambert_w0(1.0), OMEGA);
+    assert_eq!(lambert_w0(core::f64::consts::E), 1.0);
+    assert_eq!(lambert_w0(NEG_INV_E), -1.0);
+    assert_eq!(lambert_w0f(0.0), 0.0);
+    assert_eq!(lambert_w0f(1.0), consts::OMEGA_F32);
+    assert_eq!(lambert_w0f(core::f32::consts::E), 1.0);
+    assert_eq!(lambert_w0f(consts::NEG_INV_E_F32), -1.0);
+    assert_eq!(1.0.lambert_w0(), OMEGA);
+    assert_eq!(1.0_f32.lambert_w0(), consts::OMEGA_F32);
+}
+
+#[test]
+fn test_lambert_w_smooth() {
+    for z in test_arguments() {
+        let (w, smooth) = (lambert_w0(z), lambert_w0_smooth(z));
+        assert_eq!(w.is_nan(), smooth.is_nan(), "W0({z:e})");
+        if w.is_finite() {
+            assert_relative_eq!(smooth, w, max_relative = 1e-15);
+        } else if !w.is_nan() {
+            assert_eq!(smooth, w);
+        }
+        let (w, smooth) = (lambert_wm1(z), lambert_wm1_smooth(z));
+        assert_eq!(w.is_nan(), smooth.is_nan(), "W-1({z:e})");
+        if w.is_finite() {
+            assert_relative_eq!(smooth, w, max_relative = 1e-15);
+        } else if !w.is_nan() {
+            assert_eq!(smooth, w);
+        }
+    }
+
+    // Outside the transition zones at the start of the subdomains the approximations are unchanged.
+    for z in [-0.3, 0.5, 10.0, 1e10, 1e200] {
+        assert_eq!(lambert_w0_smooth(z), lambert_w0(z));
+    }
+    for z in [-0.3, -0.1, -1e-10, -1e-100] {
+        assert_eq!(lambert_wm1_smooth(z), lambert_wm1(z));
+    }
+}
+
+#[test]
+fn test_monotonicity_across_seams() {
+    // The largest arguments of every subdomain but the last of the approximations.
+    for z in [
+        2.182_014_465_332_031_3,
+        43.246_045_021_497_93,
+        598.085_654_277_611_3,
+        8_049.124_105_634_59,
+        111_124.586_241_776_65,
+        1.587_042_613_328_788_4e6,
+        2.341_470_803_399_601_6e7,
+        3.557_647_427_122_202e8,
+        5.550_171_629_248_483e9,
+        8.867_470_483_928_99e10,
+        1.447_779_186_526_922_4e12,
+        2.411_145_863_251_148_4e13,
+        4.089_703_644_260_080_6e14,
+        7.055_590_147_678_997e15,
+        1.236_660_755_797_672_8e17,
+        2.199_937_348_793_1e18,
+        3.968_539_219_834_401_6e19,
+        1.412_707_514_527_465_2e104,
+    ] {
+        assert!(
+            lambert_w0(z) <= lambert_w0(next_up(z)),
+            "W0 decreases at {z}"
+        );
+        assert!(
+            lambert_w0_smooth(z) <= lambert_w0_smooth(next_up(z)),
+            "lambert_w0_smooth decreases at {z}"
+        );
+    }
+    for z in [
+        2.008_217_811_584_472_7,
+        30.539_142_109_510_895,
+        371.669_843_713_757_76,
+        4_705.918_954_265_969,
+        64_640.797_355_310_09,
+        965_649.030_871_163_2,
+        1.559_333_422_803_816_6e7,
+        2.702_564_027_724_190_4e8,
+        4.995_018_739_704_195e9,
+        9.791_115_441_672_696e10,
+        2.025_975_385_630_21e12,
+        4.407_744_425_147_794e13,
+        1.004_838_215_057_150_5e15,
+        2.393_255_260_235_983_6e16,
+        5.939_799_659_746_575e17,
+        1.532_693_858_990_176_7e19,
+        4.103_565_939_888_539_6e20,
+        2.172_370_661_049_060_6e141,
+    ] {
+        assert!(
+            sp_lambert_w0(z) <= sp_lambert_w0(next_up(z)),
+            "sp_lambert_w0 decreases at {z}"
+        );
+        // The last subdomain of the 64-bit function is not present in the 32-bit function.
+        if z < f64::from(f32::MAX) {
+            assert!(
+                lambert_w0f(z as f32) <= lambert_w0f(next_up_f32(z as f32)),
+                "lambert_w0f decreases at {z}"
+            );
+        }
+    }
+    for z in [
+        -0.354_291_330_944_216_4,
+        -0.188_726_882_822_894_35,
+        -0.060_497_597_226_958_34,
+        -0.017_105_334_740_676_01,
+        -0.004_595_496_212_794_371,
+        -0.001_200_161_067_219_772_4,
+        -0.000_307_288_059_321_915,
+        -0.000_077_447_159_838_062_18,
+        -4.580_811_969_815_817_5e-17,
+        -6.107_367_223_659_479e-79,
+    ] {
+        assert!(
+            lambert_wm1(z) >= lambert_wm1(next_up(z)),
+            "W-1 increases at {z}"
+        );
+        assert!(
+            lambert_wm1_smooth(z) >= lambert_wm1_smooth(next_up(z)),
+            "lambert_wm1_smooth increases at {z}"
+        );
+    }
+    for z in [
+        -0.207_293_777_640_384_15,
+        -0.071_507_705_083_841_95,
+        -0.020_704_412_621_717_48,
+        -0.005_480_012_945_209_444,
+        -0.001_367_466_989_250_804_2,
+        -0.000_326_142_267_310_725_66,
+        -0.000_074_906_612_036_101_44,
+        -1.096_244_452_641_099_5e-19,
+        -2.509_609_929_994_59e-136,
+    ] {
+        assert!(
+            sp_lambert_wm1(z) >= sp_lambert_wm1(next_up(z)),
+            "sp_lambert_wm1 increases at {z}"
+        );
+        // The last subdomain of the 64-bit function is not present in the 32-bit function.
+        if z < f64::from(-f32::MIN_POSITIVE) {
+            assert!(
+                lambert_wm1f(z as f32) >= lambert_wm1f(next_up_f32(z as f32)),
+                "lambert_wm1f increases at {z}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_negative_zero() {
+    assert_eq!(lambert_w0(-0.0).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(sp_lambert_w0(-0.0).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(lambert_w0f(-0.0).to_bits(), (-0.0_f32).to_bits());
+    assert_eq!(lambert_w0(0.0).to_bits(), 0.0_f64.to_bits());
+    assert_eq!(sp_lambert_w0(0.0).to_bits(), 0.0_f64.to_bits());
+    assert_eq!(lambert_wm1(-0.0), f64::NEG_INFINITY);
+    assert_eq!(sp_lambert_wm1(-0.0), f64::NEG_INFINITY);
+    assert_eq!(lambert_wm1f(-0.0), f32::NEG_INFINITY);
+    assert_eq!(lambert_wm1f_precise(-0.0), f32::NEG_INFINITY);
+    assert!(lambert_wm1(0.0).is_nan());
+    assert!(sp_lambert_wm1(0.0).is_nan());
+    assert!(lambert_wm1f(0.0).is_nan());
+}
+
+#[test]
+fn test_lambert_w0_near_branch() {
+    assert!(lambert_w0_near_branch(-f64::MIN_POSITIVE).is_nan());
+    assert!(lambert_w0_near_branch(f64::NAN).is_nan());
+    assert_eq!(lambert_w0_near_branch(0.0), 0.0);
+    assert_relative_eq!(
+        lambert_w0_near_branch(1e-300),
+        2.331_643_981_597_124_3e-150,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(1e-20),
+        2.331_643_981_415_905_4e-10,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(1e-10),
+        2.331_625_859_911_928_6e-5,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(0.004),
+        0.140_672_496_316_261_28,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(0.0042),
+        0.143_985_450_903_214_32,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(0.01),
+        0.216_770_801_018_703_22,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        lambert_w0_near_branch(0.3),
+        0.926_978_437_815_772_7,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_lambert_wm1_near_branch() {
+    assert!(lambert_wm1_near_branch(-f64::MIN_POSITIVE).is_nan());
+    assert!(lambert_wm1_near_branch(f64::NAN).is_nan());
+    assert!(lambert_wm1_near_branch(0.5).is_nan());
+    assert_eq!(lambert_wm1_near_branch(0.0), 0.0);
+    assert_relative_eq!(
+        lambert_wm1_near_branch(1e-300),
+        -2.331_643_981_597_124_3e-150,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(1e-20),
+        -2.331_643_981_778_343e-10,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(1e-10),
+        -2.331_662_103_669_646e-5,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(0.004),
+        -0.155_245_851_821_528_6,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(0.0042),
+        -0.159_291_486_389_570_36,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(0.01),
+        -0.253_493_791_367_214_5,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        lambert_wm1_near_branch(0.3),
+        -3.101_334_473_287_480_4,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_halley_refine() {
+    assert!(halley_refine(f64::NAN, 0.0).is_nan());
+    assert!(halley_refine(1.0, f64::NAN).is_nan());
+    assert_eq!(halley_refine(0.0, 0.0), 0.0);
+    assert_abs_diff_eq!(halley_refine(1.0, 0.5), OMEGA, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        halley_refine(1.0, halley_refine(1.0, 0.5)),
+        OMEGA,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(halley_refine(1.0, sp_lambert_w0(1.0)), OMEGA);
+    assert_relative_eq!(
+        halley_refine(1e100, sp_lambert_w0(1e100)),
+        224.843_106_445_118_5,
+        max_relative = 1e-15
+    );
+    let z = -f64::ln(2.0) / 2.0;
+    assert_abs_diff_eq!(
+        halley_refine(z, sp_lambert_wm1(z)),
+        -f64::ln(4.0),
+        epsilon = 1e-15
+    );
+    assert_relative_eq!(
+        halley_refine(-1e-100, sp_lambert_wm1(-1e-100)),
+        -235.721_158_875_685_3,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_fritsch_refine() {
+    assert!(fritsch_refine(f64::NAN, 1.0).is_nan());
+    assert!(fritsch_refine(1.0, f64::NAN).is_nan());
+    assert!(fritsch_refine(1.0, -0.5).is_nan());
+    assert_eq!(fritsch_refine(core::f64::consts::E, 1.0), 1.0);
+    assert_eq!(fritsch_refine(NEG_INV_E, -1.0), -1.0);
+    assert_abs_diff_eq!(fritsch_refine(1.0, 0.5), OMEGA, epsilon = 1e-5);
+    assert_abs_diff_eq!(fritsch_refine(1.0, sp_lambert_w0(1.0)), OMEGA);
+    assert_relative_eq!(
+        fritsch_refine(f64::MAX, sp_lambert_w0(f64::MAX)),
+        703.227_033_104_770_2,
+        max_relative = 1e-15
+    );
+    let z = -f64::ln(2.0) / 2.0;
+    assert_abs_diff_eq!(
+        fritsch_refine(z, sp_lambert_wm1(z)),
+        -f64::ln(4.0),
+        epsilon = 1e-15
+    );
+    assert_relative_eq!(
+        fritsch_refine(-1e-300, sp_lambert_wm1(-1e-300)),
+        -697.322_776_295_460_2,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_lambert_w0_clamped() {
+    let below = |ulps: u64| f64::from_bits(NEG_INV_E.to_bits() + ulps);
+    assert!(lambert_w0_clamped(f64::NAN, 10).is_nan());
+    assert!(lambert_w0_clamped(f64::NEG_INFINITY, 1000).is_nan());
+    assert!(lambert_w0_clamped(below(1), 0).is_nan());
+    assert_eq!(lambert_w0_clamped(below(1), 1), -1.0);
+    assert_eq!(lambert_w0_clamped(below(10), 10), -1.0);
+    assert!(lambert_w0_clamped(below(11), 10).is_nan());
+    assert_eq!(lambert_w0_clamped(NEG_INV_E, 0), -1.0);
+    assert_eq!(lambert_w0_clamped(1.0, 10), OMEGA);
+    assert_eq!(
+        lambert_w0_clamped(-0.2, 10).to_bits(),
+        lambert_w0(-0.2).to_bits()
+    );
+}
+
+#[test]
+fn test_lambert_w0_approx() {
+    assert!(lambert_w0_approx(f64::NAN).is_nan());
+    assert!(lambert_w0_approx(-0.1).is_nan());
+    assert!(lambert_w0_approx(f64::NEG_INFINITY).is_nan());
+    assert_eq!(lambert_w0_approx(0.0), 0.0);
+    assert_eq!(lambert_w0_approx(f64::INFINITY), f64::INFINITY);
+    for i in 0..=10_000 {
+        let z = 10.0_f64.powf(-320.0 + 628.25 * f64::from(i) / 10_000.0);
+        assert_relative_eq!(lambert_w0_approx(z), lambert_w0(z), max_relative = 1.6e-4);
+    }
+    assert_relative_eq!(
+        lambert_w0_approx(f64::MAX),
+        lambert_w0(f64::MAX),
+        max_relative = 1.6e-4
+    );
+}
+
+#[test]
+fn test_lambert_w0_uniform() {
+    assert!(lambert_w0_uniform(f64::NAN).is_nan());
+    assert!(lambert_w0_uniform(-0.1).is_nan());
+    assert!(lambert_w0_uniform(f64::NEG_INFINITY).is_nan());
+    assert_eq!(lambert_w0_uniform(0.0), 0.0);
+    assert_eq!(lambert_w0_uniform(f64::INFINITY), f64::INFINITY);
+    assert_relative_eq!(lambert_w0_uniform(5e-324), 5e-324);
+    assert_relative_eq!(lambert_w0_uniform(1e-10), 9.999_999_999e-11);
+    assert_relative_eq!(
+        lambert_w0_uniform(6.321_205_588_285_577e-1),
+        4.167_039_988_177_658e-1
+    );
+    assert_relative_eq!(lambert_w0_uniform(1.0), OMEGA);
+    assert_relative_eq!(
+        lambert_w0_uniform(core::f64::consts::E),
+        1.0,
+        max_relative = 4e-16
+    );
+    assert_relative_eq!(
+        lambert_w0_uniform(9.632_120_558_828_557),
+        1.721_757_710_976_171
+    );
+    assert_relative_eq!(
+        lambert_w0_uniform(9.999_963_212_055_883e4),
+        9.284_568_107_521_96
+    );
+    assert_relative_eq!(lambert_w0_uniform(1e20), 4.230_675_509_173_839e1);
+    assert_relative_eq!(lambert_w0_uniform(1e160), 3.625_205_337_614_976e2);
+    assert_relative_eq!(
+        lambert_w0_uniform(f64::MAX),
+        703.227_033_104_770_2,
+        max_relative = 4e-16
+    );
+}
+
+#[test]
+fn test_lambert_w0_linspace() {
+    assert_eq!(lambert_w0_linspace(0.0, 1.0, 0).next(), None);
+    assert_eq!(
+        lambert_w0_linspace(1.0, 2.0, 1).collect::<Vec<_>>(),
+        [(1.0, OMEGA)]
+    );
+    assert_eq!(lambert_w0_linspace(0.0, 1.0, 7).len(), 7);
+
+    let (x, w) = lambert_w0_linspace(-1.0, 1.0, 3).next().unwrap();
+    assert_eq!(x, -1.0);
+    assert!(w.is_nan());
+    assert!(lambert_w0_linspace(f64::NAN, 1.0, 3)
+        .take(2)
+        .all(|(_, w)| w.is_nan()));
+
+    for (start, end, n) in [
+        (NEG_INV_E, 10.0, 10_000),
+        (10.0, NEG_INV_E, 10_000),
+        (-1.0, 1e3, 1_001),
+        (0.0, 1e300, 1_000),
+        (f64::MAX, 0.0, 1_000),
+        (-0.1, 0.1, 999),
+    ] {
+        let points: Vec<(f64, f64)> = lambert_w0_linspace(start, end, n).collect();
+        assert_eq!(points.len(), n);
+        assert_eq!(points[0].0, start);
+        assert_eq!(points[n - 1].0, end);
+        for (x, w) in points {
+            assert_eq!(w.to_bits(), lambert_w0(x).to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_lambert_w0_small_and_large() {
+    assert!(lambert_w0_small(f64::NAN).is_nan());
+    assert!(lambert_w0_small(-1.0).is_nan());
+    assert!(lambert_w0_small(43.25).is_nan());
+    assert!(lambert_w0_small(f64::INFINITY).is_nan());
+    assert_eq!(lambert_w0_small(NEG_INV_E), -1.0);
+    assert_eq!(lambert_w0_small(0.0), 0.0);
+    assert!(lambert_w0_large(f64::NAN).is_nan());
+    assert!(lambert_w0_large(1.0).is_nan());
+    assert!(lambert_w0_large(3.96e19).is_nan());
+    assert_eq!(lambert_w0_large(f64::INFINITY), f64::INFINITY);
+    assert_eq!(lambert_w0_large(f64::MAX), lambert_w0(f64::MAX));
+
+    for i in 0..=10_000 {
+        let z = NEG_INV_E + (43.246 - NEG_INV_E) * f64::from(i) / 10_000.0;
+        assert_eq!(lambert_w0_small(z).to_bits(), lambert_w0(z).to_bits());
+    }
+    for i in 0..=10_000 {
+        let z = 10.0_f64.powf(19.6 + 288.6 * f64::from(i) / 10_000.0);
+        assert_eq!(lambert_w0_large(z).to_bits(), lambert_w0(z).to_bits());
+    }
+}
+
+#[test]
+fn test_lambert_w0_roundtrip() {
+    assert!(lambert_w0_roundtrip(f64::NAN).is_nan());
+    assert!(lambert_w0_roundtrip(-1.0).is_nan());
+    assert_eq!(lambert_w0_roundtrip(f64::INFINITY), f64::INFINITY);
+    assert_eq!(lambert_w0_roundtrip(0.0), 0.0);
+
+    let mut rng_state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || {
+        // xorshift64
+        rng_state ^= rng_state << 13;
+        rng_state ^= rng_state >> 7;
+        rng_state ^= rng_state << 17;
+        rng_state
+    };
+
+    // Asserts that no neighbour of the result is a better preimage of z.
+    let assert_best = |z: f64| {
+        let w = lambert_w0_roundtrip(z);
+        let r = w * exp(w);
+        if r != z {
+            let up = f64::from_bits(w.to_bits() + 1);
+            let down = f64::from_bits(w.to_bits() - 1);
+            assert!((up * exp(up) - z).abs() >= (r - z).abs());
+            assert!((down * exp(down) - z).abs() >= (r - z).abs());
+        }
+        assert_relative_eq!(w, lambert_w0(z), epsilon = 1e-15, max_relative = 1e-14);
+    };
+
+    for _ in 0..10_000 {
+        // Random numbers spread over many orders of magnitude.
+        let mantissa = (next() >> 11) as f64 / (1u64 << 53) as f64;
+        let exponent = (next() % 600) as i32 - 300;
+        assert_best(mantissa * 10f64.powi(exponent));
+    }
+
+    for z in [2.5, 3.0, 10.0, 1e10, 1e300, f64::MAX] {
+        assert_best(z);
+    }
+
+    for z in [-0.3, -0.1, 1e-300, 0.5, 1.0, 2.0] {
+        let w = lambert_w0_roundtrip(z);
+        assert_eq!(w * exp(w), z);
+    }
+}
+
+#[test]
+fn test_lambert_w_with_kernel() {
+    /// A kernel with the accuracy of `f32`s.
+    struct SinglePrecision;
+
+    impl MathKernel for SinglePrecision {
+        fn sqrt(x: f64) -> f64 {
+            (x as f32).sqrt().into()
+        }
+
+        fn ln(x: f64) -> f64 {
+            (x as f32).ln().into()
+        }
+    }
+
+    // Within the range of `f32`s.
+    for z in [-0.3, -0.1, 0.5, 3.0, 1e10, 1e30] {
+        assert_eq!(lambert_w0_with_kernel::<DefaultKernel>(z), lambert_w0(z));
+        assert_relative_eq!(
+            lambert_w0_with_kernel::<SinglePrecision>(z),
+            lambert_w0(z),
+            max_relative = 1e-6
+        );
+    }
+    for z in [-0.3, -0.1, -1e-10, -1e-30] {
+        assert_eq!(lambert_wm1_with_kernel::<DefaultKernel>(z), lambert_wm1(z));
+        assert_relative_eq!(
+            lambert_wm1_with_kernel::<SinglePrecision>(z),
+            lambert_wm1(z),
+            max_relative = 1e-6
+        );
+    }
+
+    // The domain does not depend on the kernel.
+    assert!(lambert_w0_with_kernel::<SinglePrecision>(-1.0).is_nan());
+    assert!(lambert_wm1_with_kernel::<SinglePrecision>(1.0).is_nan());
+}
+
+#[test]
+fn test_lambert_w0_with_error_estimate() {
+    let (w, error) = lambert_w0_with_error_estimate(f64::NAN);
+    assert!(w.is_nan() && error.is_nan());
+    let (w, error) = lambert_w0_with_error_estimate(-1.0);
+    assert!(w.is_nan() && error.is_nan());
+    assert_eq!(lambert_w0_with_error_estimate(0.0), (0.0, 0.0));
+    assert_eq!(
+        lambert_w0_with_error_estimate(f64::INFINITY),
+        (f64::INFINITY, 0.0)
+    );
+
+    // The arguments with the function values rounded from arbitrary precision evaluations.
+    for (z, exact) in [
+        (-0.367_879_441_171_44, -0.999_999_887_716_406_1),
+        (-0.3, -0.489_402_227_180_215),
+        (-4.842_962_371_907_43e-14, -4.842_962_371_907_665e-14),
+        (1e-300, 1e-300),
+        (0.5, 0.351_733_711_249_195_84),
+        (3.0, 1.049_908_894_964_04),
+        (1e10, 20.028_685_413_304_952),
+        (1e100, 224.843_106_445_118_5),
+        (f64::MAX, 703.227_033_104_770_2),
+    ] {
+        let (w, error) = lambert_w0_with_error_estimate(z);
+        assert_eq!(w, lambert_w0(z));
+        assert!(
+            (w - exact).abs() <= error,
+            "W0({z}) = {w} ± {error}, exact: {exact}"
+        );
+        // The estimate is not needlessly large away from the branch point.
+        if z > -0.3 {
+            assert!(error < 1e-14 * exact.abs().max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_lambert_w_verified() {
+    for v in [
+        lambert_w0_verified(f64::NAN),
+        lambert_w0_verified(-1.0),
+        lambert_wm1_verified(f64::NAN),
+        lambert_wm1_verified(-1.0),
+        lambert_wm1_verified(1.0),
+    ] {
+        assert!(v.value().is_nan());
+        assert!(!v.is_certified());
+    }
+    assert_eq!(lambert_w0_verified(0.0).enclosure(), (0.0, 0.0));
+    assert_eq!(
+        lambert_w0_verified(f64::INFINITY).enclosure(),
+        (f64::INFINITY, f64::INFINITY)
+    );
+    assert_eq!(
+        lambert_wm1_verified(-0.0).enclosure(),
+        (f64::NEG_INFINITY, f64::NEG_INFINITY)
+    );
+
+    // The arguments with the function values rounded from arbitrary precision evaluations.
+    for (z, exact) in [
+        (-0.367_879_441_171_44, -0.999_999_887_716_406_1),
+        (-0.3, -0.489_402_227_180_215),
+        (-4.842_962_371_907_43e-14, -4.842_962_371_907_665e-14),
+        (1e-300, 1e-300),
+        (0.5, 0.351_733_711_249_195_84),
+        (3.0, 1.049_908_894_964_04),
+        (1e10, 20.028_685_413_304_952),
+        (1e100, 224.843_106_445_118_5),
+    ] {
+        let v = lambert_w0_verified(z);
+        assert!(v.is_certified(), "W0({z}) is not certified");
+        assert_eq!(v.value(), lambert_w0(z));
+        let (lo, hi) = v.enclosure();
+        assert!(
+            lo <= exact && exact <= hi,
+            "W0({z}) in [{lo}, {hi}], exact: {exact}"
+        );
+        // The enclosure is not needlessly wide away from the branch point.
+        if z > -0.3 {
+            assert!(hi - lo < 1e-14 * exact.abs().max(1.0));
+        }
+    }
+
+    for (z, exact) in [
+        (-0.367_879_441_171_44, -1.000_000_112_345_461_8),
+        (-0.3, -1.781_337_023_421_627_6),
+        (-1e-5, -14.163_600_815_810_183),
+        (-1e-300, -697.322_776_295_460_2),
+    ] {
+        let v = lambert_wm1_verified(z);
+        assert!(v.is_certified(), "W-1({z}) is not certified");
+        assert_eq!(v.value(), lambert_wm1(z));
+        let (lo, hi) = v.enclosure();
+        assert!(
+            lo <= exact && exact <= hi,
+            "W-1({z}) in [{lo}, {hi}], exact: {exact}"
+        );
+        if z > -0.3 {
+            assert!(hi - lo < 1e-14 * exact.abs());
+        }
+    }
+}
+
+#[test]
+fn test_lambert_w0_uncertain() {
+    for f in [lambert_w0_uncertain, lambert_w0_uncertain_second_order] {
+        for (value, sigma) in [(f64::NAN, 0.1), (-1.0, 0.1), (1.0, -0.1), (1.0, f64::NAN)] {
+            let (w, s) = f(value, sigma);
+            assert!(w.is_nan() && s.is_nan());
+        }
+        assert_eq!(f(NEG_INV_E, 0.0), (-1.0, 0.0));
+        assert_eq!(f(NEG_INV_E, 1e-3).1, f64::INFINITY);
+        assert_eq!(f(1.0, 0.0), (OMEGA, 0.0));
+    }
+
+    // Compared to arbitrary precision evaluations of the derivatives.
+    let (w, sigma) = lambert_w0_uncertain(3.0, 0.05);
+    assert_eq!(w, lambert_w0(3.0));
+    assert_relative_eq!(sigma, 0.008_536_224_036_942_365, max_relative = 1e-14);
+    let (w, sigma) = lambert_w0_uncertain_second_order(3.0, 0.05);
+    assert_relative_eq!(w, 1.049_854_688_145_275_6, max_relative = 1e-14);
+    assert_relative_eq!(sigma, 0.008_536_568_254_707_14, max_relative = 1e-14);
+}
+
+#[test]
+fn test_lambert_wf_precise() {
+    assert!(lambert_w0f_precise(f32::NAN).is_nan());
+    assert!(lambert_w0f_precise(-1.0).is_nan());
+    assert_eq!(lambert_w0f_precise(0.0), 0.0);
+    assert_eq!(lambert_w0f_precise(f32::INFINITY), f32::INFINITY);
+    assert_eq!(lambert_w0f_precise(consts::NEG_INV_E_F32), -1.0);
+    assert_eq!(lambert_w0f(consts::NEG_INV_E_F32), -1.0);
+    assert!(lambert_wm1f_precise(f32::NAN).is_nan());
+    assert!(lambert_wm1f_precise(-1.0).is_nan());
+    assert!(lambert_wm1f_precise(1.0).is_nan());
+    assert_eq!(lambert_wm1f_precise(consts::NEG_INV_E_F32), -1.0);
+
+    // The function values correctly rounded to f32 from arbitrary precision evaluations.
+    for (z, w) in [
+        (-0.367_879_42, -0.999_664_96),
+        (-0.3, -0.489_402_26),
+        (-1e-30, -1e-30),
+        (1e-40, 1e-40),
+        (0.1, 0.091_276_53),
+        (1.0, 0.567_143_26),
+        (3.0, 1.049_908_9),
+        (1e10, 20.028_685),
+        (f32::MAX, 84.288_59),
+    ] {
+        assert_eq!(lambert_w0f_precise(z), w);
+    }
+    for (z, w) in [
+        (-0.367_879_42, -1.000_335_1),
+        (-0.3, -1.781_336_9),
+        (-1e-5, -14.163_601),
+        (-1e-30, -73.373_11),
+        (-1e-40, -96.674_76),
+    ] {
+        assert_eq!(lambert_wm1f_precise(z), w);
+    }
+}
+
+#[test]
+fn test_lambert_w0_neg_exp() {
+    assert!(lambert_w0_neg_exp(f64::NAN).is_nan());
+    assert!(lambert_w0_neg_exp(0.999).is_nan());
+    assert!(lambert_w0_neg_exp(f64::NEG_INFINITY).is_nan());
+    assert_eq!(lambert_w0_neg_exp(1.0), -1.0);
+    assert_eq!(lambert_w0_neg_exp(800.0), -0.0);
+    assert!(lambert_w0_neg_exp(800.0).is_sign_negative());
+    assert_eq!(lambert_w0_neg_exp(f64::INFINITY), -0.0);
+
+    for (y, w) in [
+        (1.000_000_1, -0.999_552_853_068_551_6),
+        (1.01, -0.865_165_248_933_165_3),
+        (1.5, -0.301_709_562_684_336),
+        (1.99, -0.160_492_608_295_832_67),
+        (2.0, -0.158_594_339_563_039_37),
+        (2.01, -0.156_722_695_784_303_1),
+        (3.0, -0.052_469_097_457_714_87),
+        (5.0, -0.006_783_811_352_096_971),
+        (9.0, -0.000_123_425_036_886_336_17),
+        (30.0, -9.357_622_968_841_051e-14),
+        (100.0, -3.720_075_976_020_836e-44),
+        (700.0, -9.859_676_543_759_77e-305),
+        (708.0, -3.307_553_003_638_408e-308),
+    ] {
+        assert_relative_eq!(lambert_w0_neg_exp(y), w, max_relative = 1e-15);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_approx_builder() {
+    use lambert_w::approx_builder::{build_w0, build_wm1};
+
+    assert!(build_w0(-1.0, 0.0, 1e-10).is_none());
+    assert!(build_w0(1.0, 1.0, 1e-10).is_none());
+    assert!(build_w0(2.0, 1.0, 1e-10).is_none());
+    assert!(build_w0(1.0, f64::INFINITY, 1e-10).is_none());
+    assert!(build_w0(f64::NAN, 1.0, 1e-10).is_none());
+    assert!(build_w0(1.0, 2.0, 0.0).is_none());
+    assert!(build_wm1(-0.3, 0.0, 1e-10).is_none());
+    assert!(build_wm1(-0.4, -0.3, 1e-10).is_none());
+    // The requested error is smaller than the accuracy of the sampled function.
+    assert!(build_w0(1e10, 1e11, 1e-20).is_none());
+
+    for (lo, hi, max_error) in [
+        (0.0, 1.0, 1e-14),
+        (1.0, 2.0, 1e-12),
+        (10.0, 100.0, 1e-8),
+        (NEG_INV_E, -0.3, 1e-2),
+    ] {
+        let approximation = build_w0(lo, hi, max_error).unwrap();
+        assert_eq!(approximation.interval(), (lo, hi));
+        assert!(approximation.eval(hi + 1.0).is_nan());
+        for i in 0..=1000 {
+            let z = (lo + (hi - lo) * f64::from(i) / 1000.0).min(hi);
+            assert_abs_diff_eq!(approximation.eval(z), lambert_w0(z), epsilon = max_error);
+        }
+    }
+
+    for (lo, hi, max_error) in [(-0.3, -0.2, 1e-10), (-0.1, -0.01, 1e-6)] {
+        let approximation = build_wm1(lo, hi, max_error).unwrap();
+        for i in 0..=1000 {
+            let z = (lo + (hi - lo) * f64::from(i) / 1000.0).min(hi);
+            assert_abs_diff_eq!(approximation.eval(z), lambert_wm1(z), epsilon = max_error);
+        }
+    }
+
+    // A looser error gives a polynomial of lower degree.
+    let coarse = build_w0(1.0, 2.0, 1e-4).unwrap();
+    let fine = build_w0(1.0, 2.0, 1e-12).unwrap();
+    assert!(coarse.degree() < fine.degree());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_w0_table() {
+    use lambert_w::W0Table;
+
+    assert!(W0Table::new(NEG_INV_E..=1.0, 100).is_none());
+    assert!(W0Table::new(1.0..=1.0, 100).is_none());
+    assert!(W0Table::new(2.0..=1.0, 100).is_none());
+    assert!(W0Table::new(0.0..=f64::INFINITY, 100).is_none());
+    assert!(W0Table::new(f64::NAN..=1.0, 100).is_none());
+    assert!(W0Table::new(0.0..=1.0, 1).is_none());
+
+    for (lo, hi, n_points, max_error) in [
+        (-0.3, 0.0, 1000, 1e-12),
+        (0.0, 10.0, 1000, 1e-8),
+        (1.0, 2.0, 100, 1e-10),
+        (10.0, 1e4, 10_000, 1e-11),
+    ] {
+        let table = W0Table::new(lo..=hi, n_points).unwrap();
+        assert_eq!(table.range(), lo..=hi);
+        assert_eq!(table.n_points(), n_points);
+        for i in 0..=10_000 {
+            let z = (lo + (hi - lo) * f64::from(i) / 10_000.0).min(hi);
+            assert_abs_diff_eq!(table.eval(z), lambert_w0(z), epsilon = max_error);
+        }
+        // The table is exact at its end points and clamps arguments outside of them.
+        assert_eq!(table.eval(lo), lambert_w0(lo));
+        assert_eq!(table.eval(hi), lambert_w0(hi));
+        assert_eq!(table.eval(hi + 1.0), lambert_w0(hi));
+        assert_eq!(table.eval(lo - 1.0), lambert_w0(lo));
+        assert!(table.eval(f64::NAN).is_nan());
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_lambert_w_generic() {
+    use lambert_w::{lambert_w0_generic, lambert_wm1_generic};
+
+    assert!(lambert_w0_generic(f64::NAN).is_nan());
+    assert!(lambert_w0_generic(-1.0_f64).is_nan());
+    assert_eq!(lambert_w0_generic(0.0_f64), 0.0);
+    assert_eq!(lambert_w0_generic(NEG_INV_E), -1.0);
+    assert_eq!(lambert_w0_generic(f64::INFINITY), f64::INFINITY);
+    assert!(lambert_wm1_generic(f32::NAN).is_nan());
+    assert!(lambert_wm1_generic(0.1_f64).is_nan());
+    assert!(lambert_wm1_generic(0.0_f64).is_nan());
+    assert_eq!(lambert_wm1_generic(NEG_INV_E), -1.0);
+
+    for z in [-0.3, -1e-5, 1e-300, 0.1, 1.0, 10.0, 1e10, 1e300, f64::MAX] {
+        assert_relative_eq!(
+            lambert_w0_generic(z),
+            lambert_w0(z),
+            epsilon = 1e-15,
+            max_relative = 1e-14
+        );
+    }
+    for z in [-0.3, -1e-5, -1e-300] {
+        assert_relative_eq!(lambert_wm1_generic(z), lambert_wm1(z), max_relative = 1e-14);
+    }
+
+    // The f32 results are correctly rounded versions of the f64 results.
+    for z in [-0.3_f32, -1e-5, 0.1, 1.0, 10.0, 1e10, f32::MAX] {
+        assert_relative_eq!(
+            lambert_w0_generic(z),
+            lambert_w0(f64::from(z)) as f32,
+            max_relative = 2.0 * f32::EPSILON
+        );
+    }
+    for z in [-0.3_f32, -1e-5, -1e-30] {
+        assert_relative_eq!(
+            lambert_wm1_generic(z),
+            lambert_wm1(f64::from(z)) as f32,
+            max_relative = 2.0 * f32::EPSILON
+        );
+    }
+}
+
+#[cfg(feature = "num-dual")]
+#[test]
+fn test_lambert_w_dual() {
+    use lambert_w::{lambert_w0_dual, lambert_wm1_dual};
+    use num_dual::Dual64;
+
+    let d = |z: f64| Dual64::from_re(z).derivative();
+
+    for w in [
+        lambert_w0_dual(d(f64::NAN)),
+        lambert_w0_dual(d(-1.0)),
+        lambert_wm1_dual(d(0.1)),
+        lambert_w0_dual(d(NEG_INV_E)),
+        lambert_wm1_dual(d(NEG_INV_E)),
+    ] {
+        assert!(w.re.is_nan() && w.eps.is_nan());
+    }
+    assert_eq!(lambert_w0_dual(d(0.0)), Dual64::new(0.0, 1.0));
+    assert_eq!(
+        lambert_w0_dual(Dual64::from_re(2.0)),
+        Dual64::from_re(lambert_w0(2.0))
+    );
+    // The trait on f64s is not shadowed.
+    assert_eq!(LambertW::lambert_w0(d(1.0)).re, OMEGA);
+
+    // The arguments with the function values and derivatives rounded from arbitrary precision evaluations.
+    for (z, w, dw) in [
+        (2.0, 0.852_605_502_013_725_5, 0.230_109_837_492_917_25),
+        (-0.2, -0.259_171_101_819_073_76, 1.749_196_760_921_836),
+        (-0.36, -0.806_084_315_970_817_6, 11.546_890_140_056_811),
+    ] {
+        let result = lambert_w0_dual(d(z));
+        assert_eq!(result.re, lambert_w0(z));
+        assert_relative_eq!(result.re, w, max_relative = 1e-15);
+        assert_relative_eq!(result.eps, dw, max_relative = 1e-14);
+    }
+    for (z, w, dw) in [
+        (-0.2, -2.542_641_357_773_526, -8.241_194_056_417_904),
+        (-0.36, -1.222_770_133_978_506_2, -15.247_033_544_557_494),
+    ] {
+        let result = lambert_wm1_dual(d(z));
+        assert_eq!(result.re, lambert_wm1(z));
+        assert_relative_eq!(result.re, w, max_relative = 1e-15);
+        assert_relative_eq!(result.eps, dw, max_relative = 1e-14);
+    }
+}
+
+#[cfg(feature = "num-dual")]
+#[test]
+fn test_lambert_w_dual_second_order() {
+    use lambert_w::{lambert_w0_dual, lambert_wm1_dual};
+    use num_dual::{Dual2_64, Dual3_64, HyperDual64};
+
+    let w = lambert_w0_dual(Dual2_64::from_re(NEG_INV_E).derivative());
+    assert!(w.re.is_nan() && w.v1.is_nan() && w.v2.is_nan());
+    assert_eq!(
+        lambert_w0_dual(Dual2_64::from_re(0.0).derivative()).v2,
+        -2.0
+    );
+
+    // The Hessian of W0(x·y) at (2, 0.75) compared to arbitrary precision evaluations.
+    let (x, y) = (2.0, 0.75);
+    let xx = lambert_w0_dual(HyperDual64::new(x, 1.0, 1.0, 0.0) * y);
+    let xy =
+        lambert_w0_dual(HyperDual64::new(x, 1.0, 0.0, 0.0) * HyperDual64::new(y, 0.0, 1.0, 0.0));
+    let yy = lambert_w0_dual(HyperDual64::new(y, 1.0, 1.0, 0.0) * x);
+    assert_eq!(xy.re, lambert_w0(x * y));
+    assert_relative_eq!(xy.eps1, 0.210_289_590_904_829_4, max_relative = 1e-14);
+    assert_relative_eq!(xy.eps2, 0.560_772_242_412_878_4, max_relative = 1e-14);
+    assert_relative_eq!(xx.eps1eps2, -0.069_844_692_616_607_17, max_relative = 1e-14);
+    assert_relative_eq!(xy.eps1eps2, 0.094_133_607_562_153_4, max_relative = 1e-14);
+    assert_relative_eq!(yy.eps1eps2, -0.496_673_369_718_095_45, max_relative = 1e-14);
+
+    for (z, d2w) in [
+        (-0.2, -23.890_662_962_700_43),
+        (-0.36, 811.079_128_909_185_4),
+    ] {
+        let w = lambert_wm1_dual(Dual2_64::from_re(z).derivative());
+        assert_eq!(w.re, lambert_wm1(z));
+        assert_relative_eq!(w.v2, d2w, max_relative = 1e-14);
+    }
+
+    // Higher orders are computed with Newton's method.
+    let w = lambert_w0_dual(Dual3_64::from_re(0.5).derivative());
+    assert_eq!(w.re, lambert_w0(0.5));
+    assert_relative_eq!(w.v3, 0.930_401_645_561_780_6, max_relative = 1e-14);
+}
+
+#[cfg(feature = "ordered-float")]
+#[test]
+fn test_lambert_w_ordered_float() {
+    use ordered_float::{NotNan, OrderedFloat};
+
+    assert_eq!(OrderedFloat(1.0).lambert_w0(), OrderedFloat(OMEGA));
+    assert_eq!(
+        OrderedFloat(-0.3).lambert_wm1(),
+        OrderedFloat(lambert_wm1(-0.3))
+    );
+    assert!(OrderedFloat(-1.0).lambert_w0().is_nan());
+    assert!(OrderedFloat(1.0).lambert_wm1().is_nan());
+
+    let not_nan = |z: f64| NotNan::new(z).unwrap();
+    assert_eq!(not_nan(1.0).lambert_w0(), Ok(not_nan(OMEGA)));
+    assert_eq!(not_nan(-0.3).lambert_wm1(), Ok(not_nan(lambert_wm1(-0.3))));
+    assert_eq!(
+        not_nan(f64::INFINITY).lambert_w0(),
+        Ok(not_nan(f64::INFINITY))
+    );
+    assert_eq!(not_nan(-1.0).lambert_w0(), Err(DomainError));
+    assert_eq!(not_nan(-1.0).lambert_wm1(), Err(DomainError));
+    assert_eq!(not_nan(1.0).lambert_wm1(), Err(DomainError));
+}
+
+#[cfg(feature = "simba")]
+#[test]
+fn test_lambert_w_simd() {
+    use lambert_w::{lambert_w0_simd, lambert_wm1_simd};
+    use simba::simd::{AutoF64x4, SimdValue};
+
+    // Lanes in different parts of the domain.
+    let z = AutoF64x4::from([f64::NAN, -1.0, NEG_INV_E, 0.0]);
+    let w = lambert_w0_simd(z);
+    assert!(w.extract(0).is_nan());
+    assert!(w.extract(1).is_nan());
+    assert_eq!(w.extract(2), -1.0);
+    assert_eq!(w.extract(3), 0.0);
+    let w = lambert_wm1_simd(z);
+    assert!(w.extract(0).is_nan());
+    assert!(w.extract(1).is_nan());
+    assert_eq!(w.extract(2), -1.0);
+    assert!(w.extract(3).is_nan());
+
+    let w = lambert_w0_simd(AutoF64x4::from([f64::INFINITY, f64::MAX, 1e-300, -1e-300]));
+    assert_eq!(w.extract(0), f64::INFINITY);
+    assert_relative_eq!(w.extract(1), 703.227_033_104_770_2, max_relative = 1e-15);
+    assert_relative_eq!(w.extract(2), 1e-300, max_relative = 1e-15);
+    assert_relative_eq!(w.extract(3), -1e-300, max_relative = 1e-15);
+    let w = lambert_wm1_simd(AutoF64x4::from([-0.0, 0.1, -1e-300, -5e-324]));
+    assert_eq!(w.extract(0), f64::NEG_INFINITY);
+    assert!(w.extract(1).is_nan());
+    assert_relative_eq!(w.extract(2), -697.322_776_295_460_2, max_relative = 1e-15);
+    assert_relative_eq!(w.extract(3), lambert_wm1(-5e-324), max_relative = 1e-15);
+
+    // On both sides of the transitions between the different initial values and the Puiseux series.
+    for z in [
+        -0.367_879_441_171_44,
+        -0.367,
+        -0.366,
+        -0.3,
+        -0.19,
+        -0.18,
+        -1e-5,
+        0.1,
+        1.0,
+        10.0,
+        1e10,
+        1e300,
+    ] {
+        let w = lambert_w0_simd(AutoF64x4::splat(z));
+        assert_relative_eq!(w.extract(0), lambert_w0(z), max_relative = 3e-15);
+        assert_relative_eq!(lambert_w0_simd(z), lambert_w0(z), max_relative = 3e-15);
+        if z < 0.0 {
+            let w = lambert_wm1_simd(AutoF64x4::splat(z));
+            assert_relative_eq!(w.extract(0), lambert_wm1(z), max_relative = 3e-15);
+        }
+    }
+}
+
+#[cfg(feature = "simba")]
+#[test]
+fn test_lambert_wf_simd() {
+    use lambert_w::{consts::NEG_INV_E_F32, lambert_w0f_simd, lambert_wm1f_simd};
+    use simba::simd::{AutoF32x8, SimdValue};
+
+    // Lanes in different parts of the domain.
+    let z = AutoF32x8::from([
+        f32::NAN,
+        -1.0,
+        NEG_INV_E_F32,
+        0.0,
+        -0.0,
+        f32::INFINITY,
+        1e-45,
+        -1e-45,
+    ]);
+    let w = lambert_w0f_simd(z);
+    assert!(w.extract(0).is_nan());
+    assert!(w.extract(1).is_nan());
+    assert_eq!(w.extract(2), -1.0);
+    assert_eq!(w.extract(3), 0.0);
+    assert!(w.extract(4).is_sign_negative());
+    assert_eq!(w.extract(5), f32::INFINITY);
+    assert_eq!(w.extract(6), 1e-45);
+    assert_eq!(w.extract(7), -1e-45);
+    let w = lambert_wm1f_simd(z);
+    assert!(w.extract(0).is_nan());
+    assert!(w.extract(1).is_nan());
+    assert_eq!(w.extract(2), -1.0);
+    assert!(w.extract(3).is_nan());
+    assert_eq!(w.extract(4), f32::NEG_INFINITY);
+    assert!(w.extract(5).is_nan());
+    assert!(w.extract(6).is_nan());
+    assert_relative_eq!(w.extract(7), lambert_wm1f(-1e-45), max_relative = 1e-6);
+
+    // On both sides of the transitions between the different initial values and the Puiseux series.
+    for z in [
+        -0.367_879, -0.36, -0.33, -0.3, -0.19, -0.18, -1e-5, 0.1, 1.0, 10.0, 1e10, 1e30,
+    ] {
+        let w = lambert_w0f_simd(AutoF32x8::splat(z));
+        assert_relative_eq!(
+            f64::from(w.extract(0)),
+            lambert_w0(z.into()),
+            max_relative = 3e-7
+        );
+        if z < 0.0 {
+            let w = lambert_wm1f_simd(AutoF32x8::splat(z));
+            assert_relative_eq!(
+                f64::from(w.extract(0)),
+                lambert_wm1(z.into()),
+                max_relative = 3e-7
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lambert_w() {
+    for z in test_arguments() {
+        assert_eq!(lambert_w(Branch::W0, z).to_bits(), lambert_w0(z).to_bits());
+        assert_eq!(
+            lambert_w(Branch::Wm1, z).to_bits(),
+            lambert_wm1(z).to_bits()
+        );
+    }
+}
+
+#[test]
+fn test_branch_index() {
+    for branch in [Branch::W0, Branch::Wm1] {
+        assert_eq!(Branch::try_from(branch.index()), Ok(branch));
+    }
+    for index in [1, -2, i64::MIN, i64::MAX] {
+        let error: BranchIndexError = Branch::try_from(index).unwrap_err();
+        assert_eq!(error.index(), index);
+    }
+    assert_eq!(
+        Branch::try_from(2).unwrap_err().to_string(),
+        "the branch with index 2 does not have real values, only the branches with index 0 and -1 do"
+    );
+
+    for z in test_arguments() {
+        assert_eq!(
+            Branch::W0.is_real_at(z),
+            lambert_w0(z).is_finite(),
+            "W0({z:e})"
+        );
+        assert_eq!(
+            Branch::Wm1.is_real_at(z),
+            lambert_wm1(z).is_finite(),
+            "W-1({z:e})"
+        );
+    }
+
+    // -0.0 is equal to 0, where W0 is real and W-1 tends to -∞.
+    assert!(Branch::W0.is_real_at(-0.0));
+    assert_eq!(lambert_w0(-0.0).to_bits(), (-0.0_f64).to_bits());
+    assert!(!Branch::Wm1.is_real_at(-0.0));
+    assert_eq!(lambert_wm1(-0.0), f64::NEG_INFINITY);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_branch_deserialize() {
+    use serde::{de::IntoDeserializer, Deserialize};
+
+    let branch = |name: &str| -> Result<Branch, serde::de::value::Error> {
+        Branch::deserialize(name.into_deserializer())
+    };
+    assert_eq!(branch("W0"), Ok(Branch::W0));
+    assert_eq!(branch("Wm1"), Ok(Branch::Wm1));
+    assert!(branch("W1").is_err());
+}
+
+#[test]
+fn test_lambert_w_from_offset() {
+    // Close to the branch point z - NEG_INV_E is exact, and subtracting the rest of -1/e gives the same offset
+    // that the functions on z compute.
+    const NEG_INV_E_LO: f64 = 1.242_875_367_278_836_3e-17;
+    for k in (1..1_000_000_000).step_by(9_999_991) {
+        let z = f64::from_bits(NEG_INV_E.to_bits() - k);
+        let zc = (z - NEG_INV_E) - NEG_INV_E_LO;
+        assert_eq!(
+            lambert_w0_from_offset(zc).to_bits(),
+            lambert_w0(z).to_bits(),
+            "W0({z:e})"
+        );
+        assert_eq!(
+            lambert_wm1_from_offset(zc).to_bits(),
+            lambert_wm1(z).to_bits(),
+            "W-1({z:e})"
+        );
+    }
+
+    for z in [-0.3, -0.2, -0.125, 0.5, 1.0, 10.0, 1e10, 1e100, f64::MAX] {
+        let zc = z - NEG_INV_E;
+        assert_relative_eq!(
+            lambert_w0_from_offset(zc),
+            lambert_w0(z),
+            max_relative = 1e-15
+        );
+    }
+    for z in [-0.3, -0.2, -0.125, -0.01] {
+        let zc = z - NEG_INV_E;
+        assert_relative_eq!(
+            lambert_wm1_from_offset(zc),
+            lambert_wm1(z),
+            max_relative = 1e-15
+        );
+    }
+
+    assert_eq!(lambert_w0_from_offset(0.0), -1.0);
+    assert_eq!(lambert_wm1_from_offset(0.0), -1.0);
+    assert_eq!(lambert_w0_from_offset(f64::INFINITY), f64::INFINITY);
+    for zc in [-1e-300, f64::NAN, f64::NEG_INFINITY] {
+        assert!(lambert_w0_from_offset(zc).is_nan());
+        assert!(lambert_wm1_from_offset(zc).is_nan());
+    }
+    assert!(lambert_wm1_from_offset(1.0).is_nan());
+}
+
+#[test]
+fn test_accuracy_tiers() {
+    for z in test_arguments() {
+        assert_eq!(
+            lambert_w0_with::<Accuracy24>(z).to_bits(),
+            sp_lambert_w0(z).to_bits()
+        );
+        assert_eq!(
+            lambert_wm1_with::<Accuracy24>(z).to_bits(),
+            sp_lambert_wm1(z).to_bits()
+        );
+        assert_eq!(
+            lambert_w0_with::<Accuracy50>(z).to_bits(),
+            lambert_w0(z).to_bits()
+        );
+        assert_eq!(
+            lambert_wm1_with::<Accuracy50>(z).to_bits(),
+            lambert_wm1(z).to_bits()
+        );
+    }
+    assert_eq!(Accuracy24::ACCURACY, Accuracy::Bits24);
+    assert_eq!(Accuracy50::ACCURACY, Accuracy::Bits50);
+}
+
+#[test]
+fn test_lambert_w_with_accuracy() {
+    for z in test_arguments() {
+        assert_eq!(
+            lambert_w0_with_accuracy(z, Accuracy::Bits24).to_bits(),
+            sp_lambert_w0(z).to_bits()
+        );
+        assert_eq!(
+            lambert_wm1_with_accuracy(z, Accuracy::Bits24).to_bits(),
+            sp_lambert_wm1(z).to_bits()
+        );
+        assert_eq!(
+            lambert_w0_with_accuracy(z, Accuracy::Bits50).to_bits(),
+            lambert_w0(z).to_bits()
+        );
+        assert_eq!(
+            lambert_wm1_with_accuracy(z, Accuracy::Bits50).to_bits(),
+            lambert_wm1(z).to_bits()
+        );
+        assert_eq!(
+            lambert_w0_with_accuracy(z, Accuracy::Full).is_nan(),
+            lambert_w0(z).is_nan()
+        );
+        assert_eq!(
+            lambert_wm1_with_accuracy(z, Accuracy::Full).is_nan(),
+            lambert_wm1(z).is_nan()
+        );
+    }
+    for z in [1e-10, 0.1, 1.0, 2.0, 10.0, 1e10, 1e300] {
+        assert_relative_eq!(
+            lambert_w0_with_accuracy(z, Accuracy::Full),
+            lambert_w0_roundtrip(z),
+            max_relative = 4.0 * f64::EPSILON
+        );
+    }
+    assert_eq!(lambert_w0_with_accuracy(0.0, Accuracy::Full), 0.0);
+    assert_eq!(lambert_w0_with_accuracy(NEG_INV_E, Accuracy::Full), -1.0);
+    assert_eq!(lambert_wm1_with_accuracy(NEG_INV_E, Accuracy::Full), -1.0);
+    assert_eq!(
+        lambert_wm1_with_accuracy(-0.0, Accuracy::Full),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        lambert_w0_with_accuracy(f64::INFINITY, Accuracy::Full),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_lambert_w_segment() {
+    use SegmentId::*;
+    let w0_segments = [
+        X1, X2, X3, X4, X5, X6, X7, X8, X9, X10, X11, X12, X13, X14, X15, X16, X17, U18, U19,
+    ];
+    let wm1_segments = [Xm1, Ym1, Ym2, Ym3, Ym4, Ym5, Ym6, Ym7, Vm8, Vm9, Vm10];
+
+    for (n, w) in consts::W0_BREAKPOINTS.iter().enumerate() {
+        let below = w * (1.0 - 1e-6);
+        let above = w * (1.0 + 1e-6);
+        assert_eq!(lambert_w0_segment(below * exp(below)), Some(w0_segments[n]));
+        assert_eq!(
+            lambert_w0_segment(above * exp(above)),
+            Some(w0_segments[n + 1])
+        );
+    }
+    for (n, w) in consts::WM1_BREAKPOINTS.iter().enumerate() {
+        let above = w * (1.0 - 1e-6);
+        let below = w * (1.0 + 1e-6);
+        assert_eq!(
+            lambert_wm1_segment(above * exp(above)),
+            Some(wm1_segments[n])
+        );
+        assert_eq!(
+            lambert_wm1_segment(below * exp(below)),
+            Some(wm1_segments[n + 1])
+        );
+    }
+
+    for segment in w0_segments {
+        assert_eq!(segment.branch(), Branch::W0);
+    }
+    for segment in wm1_segments {
+        assert_eq!(segment.branch(), Branch::Wm1);
+    }
+    assert_eq!(X1.name(), "X_1");
+    assert_eq!(U19.name(), "U_19");
+    assert_eq!(Vm10.name(), "V_-10");
+
+    for z in [
+        0.0,
+        1.0,
+        core::f64::consts::E,
+        NEG_INV_E,
+        1e-10,
+        -1.0,
+        f64::INFINITY,
+        f64::NAN,
+    ] {
+        assert_eq!(lambert_w0_segment(z), None);
+    }
+    assert_eq!(lambert_w0_segment(-0.2), Some(X1));
+    assert_eq!(lambert_w0_segment(f64::MAX), Some(U19));
+    assert_eq!(lambert_wm1_segment(NEG_INV_E), Some(Xm1));
+    for z in [-1.0, -0.0, 0.0, 1.0, f64::NAN] {
+        assert_eq!(lambert_wm1_segment(z), None);
+    }
+}
+
+#[test]
+fn test_domain_policies() {
+    for z in test_arguments() {
+        let w0 = lambert_w0(z);
+        let wm1 = lambert_wm1(z);
+        assert_eq!(
+            lambert_w0_with_domain_policy::<NanPolicy>(z).to_bits(),
+            w0.to_bits()
+        );
+        assert_eq!(
+            lambert_wm1_with_domain_policy::<NanPolicy>(z).to_bits(),
+            wm1.to_bits()
+        );
+        assert_eq!(
+            lambert_w0_with_domain_policy::<ErrorPolicy>(z).is_err(),
+            w0.is_nan()
+        );
+        assert_eq!(
+            lambert_wm1_with_domain_policy::<ErrorPolicy>(z).is_err(),
+            wm1.is_nan()
+        );
+        assert_eq!(
+            lambert_w0_with_domain_policy::<ClampPolicy>(z).is_nan(),
+            z.is_nan()
+        );
+        assert_eq!(
+            lambert_wm1_with_domain_policy::<ClampPolicy>(z).is_nan(),
+            z.is_nan()
+        );
+    }
+    assert_eq!(lambert_w0_with_domain_policy::<ClampPolicy>(-1.0), -1.0);
+    assert_eq!(
+        lambert_w0_with_domain_policy::<ClampPolicy>(f64::NEG_INFINITY),
+        -1.0
+    );
+    assert_eq!(lambert_wm1_with_domain_policy::<ClampPolicy>(-1.0), -1.0);
+    assert_eq!(
+        lambert_wm1_with_domain_policy::<ClampPolicy>(0.0),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        lambert_wm1_with_domain_policy::<ClampPolicy>(f64::INFINITY),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        lambert_wm1_with_domain_policy::<ErrorPolicy>(0.0),
+        Err(DomainError)
+    );
+}
+
+#[test]
+fn test_lambert_w_solver() {
+    let w0 = LambertWSolver::new(Branch::W0);
+    let wm1 = LambertWSolver::new(Branch::Wm1);
+    for z in test_arguments() {
+        assert_eq!(w0.eval(z).to_bits(), lambert_w0(z).to_bits(), "W0({z:e})");
+        assert_eq!(
+            wm1.eval(z).to_bits(),
+            lambert_wm1(z).to_bits(),
+            "W-1({z:e})"
+        );
+    }
+
+    let w0 = w0.accuracy(Accuracy::Bits24);
+    let wm1 = wm1.accuracy(Accuracy::Bits24);
+    assert_eq!(w0.eval(2.0), sp_lambert_w0(2.0));
+    assert_eq!(wm1.eval(-0.2), sp_lambert_wm1(-0.2));
+
+    let w0 = w0.max_iterations(3).tolerance(1e-15);
+    let wm1 = wm1.max_iterations(3).tolerance(1e-15);
+    for z in [
+        -0.3678,
+        -0.3,
+        -0.1,
+        -1e-5,
+        0.1,
+        1.0,
+        10.0,
+        1e5,
+        1e100,
+        f64::MAX,
+    ] {
+        assert_relative_eq!(w0.eval(z), lambert_w0(z), max_relative = 1e-14);
+    }
+    for z in [-0.3678, -0.3, -0.1, -1e-5, -1e-100, -f64::MIN_POSITIVE] {
+        assert_relative_eq!(wm1.eval(z), lambert_wm1(z), max_relative = 1e-14);
+    }
+    for z in [0.0, NEG_INV_E, f64::INFINITY] {
+        assert_eq!(w0.eval(z), lambert_w0(z));
+    }
+    assert!(w0.eval(f64::NAN).is_nan());
+    assert!(wm1.eval(1.0).is_nan());
+
+    let below = f64::from_bits(NEG_INV_E.to_bits() + 3);
+    assert!(w0.eval(below).is_nan());
+    assert_eq!(w0.branch_point_tolerance(3).eval(below), -1.0);
+    assert!(w0.branch_point_tolerance(2).eval(below).is_nan());
+    assert!(w0.branch_point_tolerance(u64::MAX).eval(f64::NAN).is_nan());
+}
+
+#[cfg(all(lambert_w_strict_debug, debug_assertions))]
+#[test]
+#[should_panic(
+    expected = "`lambert_w0` was called with the argument -1, which is outside its domain"
+)]
+fn test_strict_debug_w0() {
+    lambert_w0(-1.0);
+}
+
+#[cfg(all(lambert_w_strict_debug, debug_assertions))]
+#[test]
+#[should_panic(
+    expected = "`lambert_wm1f` was called with the argument 1, which is outside its domain"
+)]
+fn test_strict_debug_wm1f() {
+    lambert_wm1f(1.0);
+}
+
+#[cfg(all(lambert_w_strict_debug, debug_assertions))]
+#[test]
+fn test_strict_debug_nan() {
+    // A NaN argument did not enter the computation here, so it is passed on.
+    assert!(lambert_w0(f64::NAN).is_nan());
+    assert!(sp_lambert_wm1(f64::NAN).is_nan());
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_lambert_w_f16() {
+    use half::f16;
+    use lambert_w::{lambert_w0_f16, lambert_wm1_f16};
+
+    assert!(lambert_w0_f16(f16::NAN).is_nan());
+    assert!(lambert_w0_f16(f16::NEG_ONE).is_nan());
+    assert_eq!(lambert_w0_f16(f16::ZERO), f16::ZERO);
+    assert_eq!(lambert_w0_f16(f16::INFINITY), f16::INFINITY);
+    assert!(lambert_wm1_f16(f16::NAN).is_nan());
+    assert!(lambert_wm1_f16(f16::ONE).is_nan());
+
+    // The results are at most one ulp away from the f64 results rounded to the nearest f16.
+    let assert_close = |w: f16, expected: f16| {
+        assert!(
+            (i32::from(w.to_bits()) - i32::from(expected.to_bits())).abs() <= 1,
+            "{w} != {expected}"
+        );
+    };
+
+    for bits in 0..=u16::MAX {
+        let z = f16::from_bits(bits);
+        if !z.is_finite() {
+            continue;
+        }
+        let zf = f64::from(z);
+        if zf >= NEG_INV_E {
+            assert_close(lambert_w0_f16(z), f16::from_f64(lambert_w0(zf)));
+            assert_eq!(z.lambert_w0(), lambert_w0_f16(z));
+        }
+        if (NEG_INV_E..0.0).contains(&zf) {
+            assert_close(lambert_wm1_f16(z), f16::from_f64(lambert_wm1(zf)));
+            assert_eq!(z.lambert_wm1(), lambert_wm1_f16(z));
+        }
+    }
+}
+
+#[cfg(feature = "dd")]
+#[test]
+fn test_lambert_w_dd() {
+    use lambert_w::{lambert_w0_dd, lambert_wm1_dd, DoubleDouble};
+
+    assert!(lambert_w0_dd(f64::NAN.into()).is_nan());
+    assert!(lambert_w0_dd((-1.0).into()).is_nan());
+    assert!(lambert_w0_dd(DoubleDouble::from_sum(NEG_INV_E, 0.0)).is_nan());
+    assert_eq!(lambert_w0_dd(0.0.into()), 0.0.into());
+    assert_eq!(lambert_w0_dd(f64::INFINITY.into()), f64::INFINITY.into());
+    assert!(lambert_wm1_dd(f64::NAN.into()).is_nan());
+    assert!(lambert_wm1_dd(0.0.into()).is_nan());
+    assert!(lambert_wm1_dd((-1.0).into()).is_nan());
+
+    // -1/e rounded to double-double precision.
+    let neg_inv_e = DoubleDouble::from_sum(NEG_INV_E, 1.242_875_367_278_836_3e-17);
+    assert_eq!(lambert_w0_dd(neg_inv_e), (-1.0).into());
+    assert_eq!(lambert_wm1_dd(neg_inv_e), (-1.0).into());
+
+    let assert_close = |w: DoubleDouble, hi: f64, lo: f64, max_relative: f64| {
+        let error = ((w.hi() - hi) + (w.lo() - lo)).abs();
+        assert!(
+            error <= max_relative * hi.abs(),
+            "{w:?} != ({hi}, {lo}), relative error {}",
+            error / hi.abs()
+        );
+    };
+
+    // Close to the branch point the function is ill-conditioned, and the accuracy is reduced.
+    assert_close(
+        lambert_w0_dd((-0.367_879_441_171).into()),
+        -0.999_998_449_325_332,
+        4.344_654_294_244_349e-17,
+        1e-25,
+    );
+    assert_close(
+        lambert_wm1_dd((-0.367_879_441_171).into()),
+        -1.000_001_550_676_271_2,
+        5.565_971_152_798_878e-17,
+        1e-25,
+    );
+
+    for (z, hi, lo) in [
+        (-0.3, -0.489_402_227_180_214_9, -1.202_252_109_672_828e-17),
+        (-0.1, -0.111_832_559_158_962_97, -5.983_634_201_106_934e-18),
+        (
+            -1e-10,
+            -1.000_000_000_100_000_1e-10,
+            6.143_503_760_230_586e-27,
+        ),
+        (1e-300, 1e-300, 0.0),
+        (5e-324, 5e-324, 0.0),
+        (1e-5, 9.999_900_001_499_974e-6, 1.762_659_633_746_207e-22),
+        (0.5, 0.351_733_711_249_195_84, -9.055_263_099_991_632e-18),
+        (2.0, 0.852_605_502_013_725_5, -4.445_722_853_535_96e-17),
+        (10.0, 1.745_528_002_740_699_4, -3.058_975_929_261_855e-17),
+        (1e5, 9.284_571_428_622_108, 8.252_809_013_257_846e-16),
+        (1e100, 224.843_106_445_118_5, -6.408_137_680_823_115e-15),
+        (1e300, 684.247_208_629_760_8, 8.391_548_055_033_588e-16),
+        (f64::MAX, 703.227_033_104_770_2, 2.939_677_933_893_915e-14),
+    ] {
+        assert_close(lambert_w0_dd(z.into()), hi, lo, 1e-30);
+    }
+
+    for (z, hi, lo) in [
+        (-0.3, -1.781_337_023_421_627_7, 2.607_618_991_395_831_7e-19),
+        (-0.1, -3.577_152_063_957_297, -1.794_970_675_198_664e-17),
+        (-1e-5, -14.163_600_815_810_183, -2.118_498_388_319_292_8e-16),
+        (-1e-100, -235.721_158_875_685_32, 5.249_353_130_198_946e-15),
+        (
+            -1e-300,
+            -697.322_776_295_460_1,
+            -3.065_672_646_981_625_5e-14,
+        ),
+        (-5e-324, -751.061_559_539_879_1, 3.228_840_057_205_811_6e-14),
+    ] {
+        assert_close(lambert_wm1_dd(z.into()), hi, lo, 1e-30);
+    }
+    assert_eq!(lambert_wm1_dd((-0.0).into()).hi(), f64::NEG_INFINITY);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_lambert_w0_decimal() {
+    use core::str::FromStr;
+    use lambert_w::{lambert_w0_decimal, DomainError};
+    use rust_decimal::Decimal;
+
+    assert_eq!(lambert_w0_decimal(Decimal::NEGATIVE_ONE), Err(DomainError));
+    assert_eq!(
+        lambert_w0_decimal(Decimal::from_str("-0.3678794411714423215955237703").unwrap()),
+        Err(DomainError)
+    );
+    assert_eq!(
+        lambert_w0_decimal(Decimal::from_str("-0.3678794411714423215955237702").unwrap()),
+        Ok(Decimal::NEGATIVE_ONE)
+    );
+    assert_eq!(lambert_w0_decimal(Decimal::ZERO), Ok(Decimal::ZERO));
+
+    // The accuracy is reduced close to the branch point, where the function is ill-conditioned.
+    for (z, w, tolerance) in [
+        (
+            "-0.3678794411714423215955237701",
+            "-0.9999999999999817206149370786",
+            "0.0000000000001",
+        ),
+        (
+            "-0.3678794",
+            "-0.9995269666075681262606395539",
+            "0.000000000000000000000001",
+        ),
+        (
+            "-0.3",
+            "-0.4894022271802149690362312520",
+            "0.000000000000000000000000001",
+        ),
+        (
+            "-0.0000000001",
+            "-0.0000000001000000000100000000",
+            "0.0000000000000000000000000001",
+        ),
+        (
+            "0.000000000000000000000001",
+            "0.0000000000000000000000010000",
+            "0.0000000000000000000000000001",
+        ),
+        (
+            "0.5",
+            "0.3517337112491958260249093009",
+            "0.000000000000000000000000001",
+        ),
+        (
+            "2",
+            "0.8526055020137254913464724147",
+            "0.000000000000000000000000001",
+        ),
+        (
+            "10",
+            "1.745528002740699383074301265",
+            "0.000000000000000000000000001",
+        ),
+        (
+            "1000000",
+            "11.38335808614005262200015678",
+            "0.00000000000000000000000001",
+        ),
+        (
+            "79228162514264337593543950335",
+            "62.40842898757278873826113196",
+            "0.0000000000000000000000001",
+        ),
+    ] {
+        let z = Decimal::from_str(z).unwrap();
+        let w = Decimal::from_str(w).unwrap();
+        let tolerance = Decimal::from_str(tolerance).unwrap();
+        let result = lambert_w0_decimal(z).unwrap();
+        assert!((result - w).abs() <= tolerance, "W0({z}) = {result} != {w}");
+    }
+}
+
+/// Arguments on both sides of every subdomain boundary, the special cases of the functions on single arguments,
+/// and arguments spread over the domains of both branches.
+fn test_arguments() -> Vec<f64> {
+    let mut z = vec![
+        0.0,
+        -0.0,
+        1.0,
+        core::f64::consts::E,
+        NEG_INV_E,
+        next_up(NEG_INV_E),
+        -0.125,
+        0.125,
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        5e-324,
+        -5e-324,
+        f64::MAX,
+        -f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    for w in consts::W0_BREAKPOINTS
+        .iter()
+        .chain(&consts::WM1_BREAKPOINTS)
+    {
+        let boundary = w * exp(*w);
+        z.extend([boundary, next_up(boundary), -next_up(-boundary)]);
+    }
+    for i in 0..2000 {
+        let t = f64::from(i) * 0.618_033_988_749_895 % 1.0;
+        z.push(NEG_INV_E + 10.0_f64.powf(-16.0 + 20.0 * t));
+        z.push(-10.0_f64.powf(-300.0 * t) / 3.0);
+        z.push(10.0_f64.powf(300.0 * t));
+    }
+    z
+}
+
+/// The exponential function that the crate uses internally.
+fn exp(x: f64) -> f64 {
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+    {
+        x.exp()
+    }
+    #[cfg(any(not(feature = "std"), feature = "deterministic"))]
+    {
+        libm::exp(x)
+    }
+}
+
+/// The smallest float that is larger than the given finite and non-zero float.
+fn next_up(x: f64) -> f64 {
+    if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// The smallest float that is larger than the given finite and non-zero float.
+fn next_up_f32(x: f32) -> f32 {
+    if x > 0.0 {
+        f32::from_bits(x.to_bits() + 1)
+    } else {
+        f32::from_bits(x.to_bits() - 1)
+    }
+}